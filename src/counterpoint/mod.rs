@@ -0,0 +1,5 @@
+mod part_writing;
+mod species;
+
+pub use self::part_writing::*;
+pub use self::species::*;
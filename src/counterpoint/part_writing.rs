@@ -0,0 +1,478 @@
+use crate::harmony::{Dyad, IdentifyChords, VoiceRanges};
+use crate::interval::IntervalRoot::{Fifth, Second, Seventh, Unison};
+use crate::interval::{augmented, major, Interval, IsPerfect};
+use crate::key::Key;
+use crate::pitch::{Pitch, PitchClass, PitchSet};
+use crate::vertical::{Semitones, SemitonesFromC0, StepsFromC0, TransposedBy};
+use std::fmt::{Display, Formatter};
+
+/// The pitches of a four-part chord, from the bass up.
+pub type FourPartChord = [Pitch; 4];
+
+/// One of the voices in four-part writing, from the bass up.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Voice {
+    Bass,
+    Tenor,
+    Alto,
+    Soprano,
+}
+
+pub const VOICES: [Voice; 4] = [Voice::Bass, Voice::Tenor, Voice::Alto, Voice::Soprano];
+
+impl Voice {
+    /// Returns the index of the voice in a four-part chord.
+    pub fn index(&self) -> usize {
+        match *self {
+            Self::Bass => 0,
+            Self::Tenor => 1,
+            Self::Alto => 2,
+            Self::Soprano => 3,
+        }
+    }
+}
+
+impl Display for Voice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Bass => "bass",
+            Self::Tenor => "tenor",
+            Self::Alto => "alto",
+            Self::Soprano => "soprano",
+        })
+    }
+}
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A rule of common-practice [part writing](https://en.wikipedia.org/wiki/Voice_leading#Common-practice_conventions_and_pedagogy).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Rule {
+    ParallelFifths,
+    ParallelOctaves,
+    HiddenFifths,
+    HiddenOctaves,
+    VoiceCrossing,
+    VoiceOverlap,
+    Spacing,
+    OutOfRange,
+    DoubledLeadingTone,
+    UnresolvedSeventh,
+    AugmentedSecond,
+}
+
+impl Rule {
+    pub fn severity(&self) -> Severity {
+        match *self {
+            Self::HiddenFifths | Self::HiddenOctaves | Self::VoiceOverlap | Self::Spacing => {
+                Severity::Warning
+            }
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::ParallelFifths => "parallel fifths",
+            Self::ParallelOctaves => "parallel octaves",
+            Self::HiddenFifths => "hidden fifths",
+            Self::HiddenOctaves => "hidden octaves",
+            Self::VoiceCrossing => "voice crossing",
+            Self::VoiceOverlap => "voice overlap",
+            Self::Spacing => "spacing over an octave",
+            Self::OutOfRange => "out of range",
+            Self::DoubledLeadingTone => "doubled leading tone",
+            Self::UnresolvedSeventh => "unresolved seventh",
+            Self::AugmentedSecond => "augmented second",
+        })
+    }
+}
+
+/// A broken rule, at the index of the chord where it happens and in the voices involved.
+/// Rules about motion are reported at the chord that is moved to.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Finding {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub chord: usize,
+    pub voices: Vec<Voice>,
+}
+
+impl Finding {
+    fn new(rule: Rule, chord: usize, voices: Vec<Voice>) -> Self {
+        Finding {
+            rule,
+            severity: rule.severity(),
+            chord,
+            voices,
+        }
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let voices = self
+            .voices
+            .iter()
+            .map(|voice| voice.to_string())
+            .collect::<Vec<_>>();
+
+        write!(
+            f,
+            "{} at chord {} ({})",
+            self.rule,
+            self.chord + 1,
+            voices.join(", ")
+        )
+    }
+}
+
+/// Checks a sequence of four-part chords in a key against the rules of common-practice part writing.
+///
+/// # Example
+///
+/// ```
+/// use solfege::counterpoint::{check_part_writing, Rule, Voice};
+/// use solfege::harmony::VoiceRanges;
+/// use solfege::key::Key;
+/// use solfege::pitch::PitchRoot::{A, B, C, D, E, F, G};
+/// use solfege::pitch::PitchClassUtils;
+///
+/// let key = Key::try_from("C major").unwrap();
+///
+/// let chords = [
+///     [C.o(3), G.o(3), E.o(4), C.o(5)],
+///     [D.o(3), A.o(3), F.o(4), D.o(5)],
+/// ];
+///
+/// let findings = check_part_writing(&chords, &key, &VoiceRanges::default());
+///
+/// assert!(findings.iter().any(|finding| {
+///     finding.rule == Rule::ParallelFifths && finding.voices == vec![Voice::Bass, Voice::Tenor]
+/// }));
+/// assert!(findings.iter().any(|finding| {
+///     finding.rule == Rule::ParallelOctaves && finding.voices == vec![Voice::Bass, Voice::Soprano]
+/// }));
+/// ```
+pub fn check_part_writing(
+    chords: &[FourPartChord],
+    key: &Key,
+    ranges: &VoiceRanges,
+) -> Vec<Finding> {
+    let mut findings = vec![];
+
+    let leading_tone = key.tonic.transposed_by(&major(Seventh));
+
+    for (index, chord) in chords.iter().enumerate() {
+        check_chord(index, chord, &leading_tone, ranges, &mut findings);
+
+        if index > 0 {
+            check_motion(index, &chords[index - 1], chord, &mut findings);
+        }
+
+        check_seventh(index, chord, chords.get(index + 1), &mut findings);
+    }
+
+    findings
+}
+
+fn semitones(pitch: &Pitch) -> Semitones {
+    pitch.semitones_from_c0()
+}
+
+fn interval(low: &Pitch, high: &Pitch) -> Interval {
+    Interval::from(&Dyad::from((*low, *high)))
+}
+
+fn check_chord(
+    index: usize,
+    chord: &FourPartChord,
+    leading_tone: &PitchClass,
+    ranges: &VoiceRanges,
+    findings: &mut Vec<Finding>,
+) {
+    for voice in VOICES {
+        let range = match voice {
+            Voice::Bass => ranges.bass,
+            Voice::Tenor => ranges.tenor,
+            Voice::Alto => ranges.alto,
+            Voice::Soprano => ranges.soprano,
+        };
+
+        let pitch = chord[voice.index()];
+
+        if semitones(&pitch) < semitones(&range.low) || semitones(&pitch) > semitones(&range.high) {
+            findings.push(Finding::new(Rule::OutOfRange, index, vec![voice]));
+        }
+    }
+
+    for pair in VOICES.windows(2) {
+        let (lower, upper) = (chord[pair[0].index()], chord[pair[1].index()]);
+
+        if semitones(&upper) < semitones(&lower) {
+            findings.push(Finding::new(Rule::VoiceCrossing, index, pair.to_vec()));
+        }
+
+        if pair[0] != Voice::Bass && semitones(&upper) - semitones(&lower) > Semitones(12) {
+            findings.push(Finding::new(Rule::Spacing, index, pair.to_vec()));
+        }
+    }
+
+    let leading_tones = VOICES
+        .into_iter()
+        .filter(|voice| chord[voice.index()].class == *leading_tone)
+        .collect::<Vec<_>>();
+
+    if leading_tones.len() > 1 {
+        findings.push(Finding::new(Rule::DoubledLeadingTone, index, leading_tones));
+    }
+}
+
+fn check_motion(
+    index: usize,
+    previous: &FourPartChord,
+    current: &FourPartChord,
+    findings: &mut Vec<Finding>,
+) {
+    for voice in VOICES {
+        let (from, to) = (previous[voice.index()], current[voice.index()]);
+
+        let leap = interval(&from, &to);
+
+        if leap.octaves.0 == 0 && leap.class == augmented(Second) {
+            findings.push(Finding::new(Rule::AugmentedSecond, index, vec![voice]));
+        }
+    }
+
+    for pair in VOICES.windows(2) {
+        let (lower, upper) = (pair[0].index(), pair[1].index());
+
+        if semitones(&current[upper]) < semitones(&previous[lower])
+            || semitones(&current[lower]) > semitones(&previous[upper])
+        {
+            findings.push(Finding::new(Rule::VoiceOverlap, index, pair.to_vec()));
+        }
+    }
+
+    for lower in VOICES {
+        for upper in VOICES.into_iter().filter(|upper| *upper > lower) {
+            let (l, u) = (lower.index(), upper.index());
+
+            let lower_motion = semitones(&current[l]) - semitones(&previous[l]);
+            let upper_motion = semitones(&current[u]) - semitones(&previous[u]);
+
+            let is_similar =
+                lower_motion.0.signum() == upper_motion.0.signum() && lower_motion.0 != 0;
+
+            if !is_similar {
+                continue;
+            }
+
+            let before = interval(&previous[l], &previous[u]);
+            let after = interval(&current[l], &current[u]);
+
+            let is_perfect =
+                |interval: &Interval, root| interval.is_perfect() && interval.class.root == root;
+
+            for (root, parallel, hidden) in [
+                (Fifth, Rule::ParallelFifths, Rule::HiddenFifths),
+                (Unison, Rule::ParallelOctaves, Rule::HiddenOctaves),
+            ] {
+                if !is_perfect(&after, root) {
+                    continue;
+                }
+
+                if is_perfect(&before, root) {
+                    findings.push(Finding::new(parallel, index, vec![lower, upper]));
+                } else if lower == Voice::Bass
+                    && upper == Voice::Soprano
+                    && upper_motion.0.abs() > 2
+                {
+                    findings.push(Finding::new(hidden, index, vec![lower, upper]));
+                }
+            }
+        }
+    }
+}
+
+/// Checks whether the chordal seventh moves down by step, or is held, into the next chord.
+fn check_seventh(
+    index: usize,
+    chord: &FourPartChord,
+    next: Option<&FourPartChord>,
+    findings: &mut Vec<Finding>,
+) {
+    let next = match next {
+        Some(next) => next,
+        None => return,
+    };
+
+    let pitches = chord.iter().copied().collect::<PitchSet>();
+
+    let symbol = match pitches.identify_chords().into_iter().next() {
+        Some(candidate) => candidate.symbol,
+        None => return,
+    };
+
+    let seventh = match symbol
+        .intervals()
+        .into_iter()
+        .find(|interval| interval.root == Seventh)
+    {
+        Some(interval) => symbol.root.transposed_by(&interval),
+        None => return,
+    };
+
+    for voice in VOICES
+        .into_iter()
+        .filter(|voice| chord[voice.index()].class == seventh)
+    {
+        let (from, to) = (chord[voice.index()], next[voice.index()]);
+
+        let steps = to.steps_from_c0() - from.steps_from_c0();
+
+        if from != to && steps.0 != -1 {
+            findings.push(Finding::new(
+                Rule::UnresolvedSeventh,
+                index + 1,
+                vec![voice],
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::counterpoint::{check_part_writing, Finding, FourPartChord, Rule, Severity, Voice};
+    use crate::harmony::VoiceRanges;
+    use crate::key::Key;
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassUtils, PitchRootUtils};
+
+    fn check(chords: &[FourPartChord], key: &str) -> Vec<Finding> {
+        check_part_writing(
+            chords,
+            &Key::try_from(key).unwrap(),
+            &VoiceRanges::default(),
+        )
+    }
+
+    fn has(findings: &[Finding], rule: Rule, chord: usize, voices: &[Voice]) -> bool {
+        findings.iter().any(|finding| {
+            finding.rule == rule && finding.chord == chord && finding.voices == voices
+        })
+    }
+
+    #[test]
+    fn correct_progression() {
+        let chords = [
+            [C.o(3), G.o(3), E.o(4), C.o(5)],
+            [F.o(3), A.o(3), F.o(4), C.o(5)],
+            [G.o(2), B.o(3), F.o(4), D.o(5)],
+            [C.o(3), C.o(4), E.o(4), C.o(5)],
+        ];
+
+        assert_eq!(check(&chords, "C major"), vec![]);
+    }
+
+    #[test]
+    fn unresolved_seventh() {
+        let chords = [
+            [G.o(2), B.o(3), F.o(4), D.o(5)],
+            [C.o(3), C.o(4), G.o(4), C.o(5)],
+        ];
+
+        let findings = check(&chords, "C major");
+
+        assert!(has(&findings, Rule::UnresolvedSeventh, 1, &[Voice::Alto]));
+    }
+
+    #[test]
+    fn doubled_leading_tone() {
+        let findings = check(&[[G.o(2), B.o(3), D.o(4), B.o(4)]], "C major");
+
+        assert!(has(
+            &findings,
+            Rule::DoubledLeadingTone,
+            0,
+            &[Voice::Tenor, Voice::Soprano]
+        ));
+    }
+
+    #[test]
+    fn crossing_spacing_and_range() {
+        let findings = check(&[[C.o(3), E.o(4), C.o(4), A.o(5)]], "C major");
+
+        assert!(has(
+            &findings,
+            Rule::VoiceCrossing,
+            0,
+            &[Voice::Tenor, Voice::Alto]
+        ));
+        assert!(has(
+            &findings,
+            Rule::Spacing,
+            0,
+            &[Voice::Alto, Voice::Soprano]
+        ));
+        assert!(has(&findings, Rule::OutOfRange, 0, &[Voice::Soprano]));
+        assert_eq!(findings.len(), 3);
+    }
+
+    #[test]
+    fn augmented_second() {
+        let chords = [
+            [D.o(3), A.o(3), F.o(4), D.o(5)],
+            [E.o(3), B.o(3), G.sharp().o(4), E.o(5)],
+        ];
+
+        let findings = check(&chords, "A minor");
+
+        assert!(has(&findings, Rule::AugmentedSecond, 1, &[Voice::Alto]));
+    }
+
+    #[test]
+    fn hidden_octaves() {
+        let chords = [
+            [C.o(3), G.o(3), C.o(4), E.o(4)],
+            [D.o(3), A.o(3), F.o(4), D.o(5)],
+        ];
+
+        let findings = check(&chords, "C major");
+
+        assert!(has(
+            &findings,
+            Rule::HiddenOctaves,
+            1,
+            &[Voice::Bass, Voice::Soprano]
+        ));
+        assert_eq!(Rule::HiddenOctaves.severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn overlap() {
+        let chords = [
+            [C.o(3), G.o(3), E.o(4), C.o(5)],
+            [C.o(3), F.o(4), G.o(4), C.o(5)],
+        ];
+
+        let findings = check(&chords, "C major");
+
+        assert!(has(
+            &findings,
+            Rule::VoiceOverlap,
+            1,
+            &[Voice::Tenor, Voice::Alto]
+        ));
+        assert_eq!(
+            findings[0].to_string(),
+            "voice overlap at chord 2 (tenor, alto)"
+        );
+    }
+}
@@ -0,0 +1,531 @@
+use crate::harmony::Dyad;
+use crate::interval::IntervalQuality::Imperfect;
+use crate::interval::IntervalRoot::{Fifth, Sixth, Third, Unison};
+use crate::interval::{GetIntervalQuality, Interval, IsPerfect};
+use crate::pitch::Pitch;
+use crate::vertical::{Semitones, SemitonesFromC0, StepsFromC0};
+use std::fmt::{Display, Formatter};
+
+/// The five [species](https://en.wikipedia.org/wiki/Counterpoint#Species_counterpoint) of Fux.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Species {
+    /// Note against note.
+    First,
+    /// Two notes against one.
+    Second,
+    /// Four notes against one.
+    Third,
+    /// Two notes against one, tied over the barline.
+    Fourth,
+    /// A mix of the other species.
+    Fifth,
+}
+
+impl Species {
+    /// Returns whether a bar may hold a number of notes.
+    fn allows(&self, notes: usize) -> bool {
+        match *self {
+            Self::First => notes == 1,
+            Self::Second | Self::Fourth => notes == 2,
+            Self::Third => notes == 4,
+            Self::Fifth => (1..=4).contains(&notes),
+        }
+    }
+
+    fn allows_suspensions(&self) -> bool {
+        matches!(*self, Self::Fourth | Self::Fifth)
+    }
+
+    fn allows_neighbors(&self) -> bool {
+        matches!(*self, Self::Third | Self::Fifth)
+    }
+}
+
+/// A rule of species counterpoint.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum SpeciesRule {
+    /// The counterpoint does not have a bar for every cantus firmus note.
+    LengthMismatch,
+    NotesPerBar,
+    DissonantDownbeat,
+    UntreatedDissonance,
+    UnresolvedSuspension,
+    ParallelPerfects,
+    Beginning,
+    Ending,
+    RepeatedClimax,
+    UnrecoveredLeap,
+}
+
+impl Display for SpeciesRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::LengthMismatch => {
+                "different numbers of bars in the cantus firmus and counterpoint"
+            }
+            Self::NotesPerBar => "wrong number of notes in the bar",
+            Self::DissonantDownbeat => "dissonance on a downbeat",
+            Self::UntreatedDissonance => "dissonance that is not passing",
+            Self::UnresolvedSuspension => "suspension that does not resolve down by step",
+            Self::ParallelPerfects => "parallel perfect consonances",
+            Self::Beginning => "beginning on an imperfect consonance",
+            Self::Ending => "ending without a step into a unison or octave",
+            Self::RepeatedClimax => "climax reached more than once",
+            Self::UnrecoveredLeap => "leap not followed by motion in the other direction",
+        })
+    }
+}
+
+/// A broken rule, at a bar of the cantus firmus and a note within that bar.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Violation {
+    pub rule: SpeciesRule,
+    pub bar: usize,
+    pub note: usize,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at bar {}, note {}",
+            self.rule,
+            self.bar + 1,
+            self.note + 1
+        )
+    }
+}
+
+/// A counterpoint note together with where it is and the cantus firmus note below or above it.
+#[derive(Copy, Clone, Debug)]
+struct Placed {
+    bar: usize,
+    note: usize,
+    pitch: Pitch,
+    cantus: Pitch,
+}
+
+impl Placed {
+    fn interval(&self) -> Interval {
+        Interval::from(&Dyad::from((self.pitch, self.cantus)))
+    }
+
+    fn is_consonant(&self) -> bool {
+        let interval = self.interval();
+
+        match interval.class.root {
+            Unison | Fifth => interval.is_perfect(),
+            Third | Sixth => matches!(
+                interval.get_interval_quality(),
+                Imperfect(Semitones(0)) | Imperfect(Semitones(-1))
+            ),
+            _ => false,
+        }
+    }
+
+    fn is_perfect_consonance(&self) -> bool {
+        let interval = self.interval();
+
+        interval.is_perfect() && matches!(interval.class.root, Unison | Fifth)
+    }
+
+    fn is_unison_or_octave(&self) -> bool {
+        let interval = self.interval();
+
+        interval.is_perfect() && interval.class.root == Unison
+    }
+
+    fn violation(&self, rule: SpeciesRule) -> Violation {
+        Violation {
+            rule,
+            bar: self.bar,
+            note: self.note,
+        }
+    }
+}
+
+fn steps_between(from: &Pitch, to: &Pitch) -> i32 {
+    (to.steps_from_c0() - from.steps_from_c0()).0
+}
+
+fn semitones_between(from: &Pitch, to: &Pitch) -> i32 {
+    (to.semitones_from_c0() - from.semitones_from_c0()).0
+}
+
+/// Validates a counterpoint line against a cantus firmus following the rules of Fux.
+/// The counterpoint holds the notes sounding against every cantus firmus note;
+/// a note repeated over the barline is tied.
+/// The first bar may start with a rest, and the last bar holds a single note.
+///
+/// If the lines have different numbers of bars, only a length mismatch is reported, at the first unmatched bar.
+///
+/// # Example
+///
+/// ```
+/// use solfege::counterpoint::{check_species, Species, SpeciesRule};
+/// use solfege::pitch::PitchRoot::{A, B, C, D, E, F};
+/// use solfege::pitch::{PitchClassUtils, PitchRootUtils};
+///
+/// let cantus = [D.o(4), F.o(4), E.o(4), D.o(4)];
+///
+/// let counterpoint = [
+///     vec![A.o(4)],
+///     vec![A.o(4)],
+///     vec![C.o(5)],
+///     vec![D.o(5)],
+/// ];
+///
+/// assert_eq!(check_species(&cantus, &counterpoint, Species::First), vec![]);
+///
+/// // A fourth over the cantus is dissonant
+/// let counterpoint = [
+///     vec![A.o(4)],
+///     vec![B.flat().o(4)],
+///     vec![C.o(5)],
+///     vec![D.o(5)],
+/// ];
+///
+/// let violations = check_species(&cantus, &counterpoint, Species::First);
+///
+/// assert_eq!(violations[0].rule, SpeciesRule::DissonantDownbeat);
+/// assert_eq!(violations[0].bar, 1);
+/// ```
+pub fn check_species(
+    cantus: &[Pitch],
+    counterpoint: &[Vec<Pitch>],
+    species: Species,
+) -> Vec<Violation> {
+    if cantus.len() != counterpoint.len() {
+        return vec![Violation {
+            rule: SpeciesRule::LengthMismatch,
+            bar: cantus.len().min(counterpoint.len()),
+            note: 0,
+        }];
+    }
+
+    let mut violations = vec![];
+
+    for (bar, notes) in counterpoint.iter().enumerate() {
+        let is_last = bar == counterpoint.len() - 1;
+
+        let is_allowed = if is_last {
+            notes.len() == 1
+        } else {
+            species.allows(notes.len()) || (bar == 0 && species.allows(notes.len() + 1))
+        };
+
+        if !is_allowed {
+            violations.push(Violation {
+                rule: SpeciesRule::NotesPerBar,
+                bar,
+                note: 0,
+            });
+        }
+    }
+
+    let placed = counterpoint
+        .iter()
+        .enumerate()
+        .flat_map(|(bar, notes)| {
+            // A first bar that starts with a rest has its notes on the later beats
+            let offset = match bar == 0 && !species.allows(notes.len()) {
+                true => 1,
+                false => 0,
+            };
+
+            notes.iter().enumerate().map(move |(note, pitch)| Placed {
+                bar,
+                note: note + offset,
+                pitch: *pitch,
+                cantus: cantus[bar],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if placed.is_empty() {
+        return violations;
+    }
+
+    check_dissonances(&placed, species, &mut violations);
+    check_parallels(&placed, &mut violations);
+    check_beginning_and_ending(&placed, &mut violations);
+    check_melody(&placed, &mut violations);
+
+    violations.sort_by_key(|violation| (violation.bar, violation.note));
+
+    violations
+}
+
+fn check_dissonances(placed: &[Placed], species: Species, violations: &mut Vec<Violation>) {
+    for (index, current) in placed.iter().enumerate() {
+        if current.is_consonant() {
+            continue;
+        }
+
+        let previous = index.checked_sub(1).map(|index| &placed[index]);
+        let next = placed.get(index + 1);
+
+        if current.note == 0 {
+            let is_tied = previous.is_some_and(|previous| previous.pitch == current.pitch);
+
+            if !is_tied || !species.allows_suspensions() {
+                violations.push(current.violation(SpeciesRule::DissonantDownbeat));
+                continue;
+            }
+
+            let resolves = next.is_some_and(|next| {
+                steps_between(&current.pitch, &next.pitch) == -1 && next.is_consonant()
+            });
+
+            if !resolves {
+                violations.push(current.violation(SpeciesRule::UnresolvedSuspension));
+            }
+
+            continue;
+        }
+
+        let (previous, next) = match (previous, next) {
+            (Some(previous), Some(next)) => (previous, next),
+            _ => {
+                violations.push(current.violation(SpeciesRule::UntreatedDissonance));
+                continue;
+            }
+        };
+
+        let approach = steps_between(&previous.pitch, &current.pitch);
+        let departure = steps_between(&current.pitch, &next.pitch);
+
+        let is_passing = approach.abs() == 1 && approach == departure;
+        let is_neighbor =
+            species.allows_neighbors() && approach.abs() == 1 && approach == -departure;
+
+        if !is_passing && !is_neighbor {
+            violations.push(current.violation(SpeciesRule::UntreatedDissonance));
+        }
+    }
+}
+
+fn check_parallels(placed: &[Placed], violations: &mut Vec<Violation>) {
+    let downbeats = placed
+        .iter()
+        .filter(|placed| placed.note == 0)
+        .copied()
+        .collect::<Vec<_>>();
+
+    for line in [placed, &downbeats] {
+        for pair in line.windows(2) {
+            let (before, after) = (&pair[0], &pair[1]);
+
+            let is_same_perfect = before.is_perfect_consonance()
+                && after.is_perfect_consonance()
+                && before.interval().class == after.interval().class;
+
+            let counterpoint_motion = semitones_between(&before.pitch, &after.pitch);
+            let cantus_motion = semitones_between(&before.cantus, &after.cantus);
+
+            let is_similar =
+                counterpoint_motion.signum() == cantus_motion.signum() && cantus_motion != 0;
+
+            let violation = after.violation(SpeciesRule::ParallelPerfects);
+
+            if is_same_perfect && is_similar && !violations.contains(&violation) {
+                violations.push(violation);
+            }
+        }
+    }
+}
+
+fn check_beginning_and_ending(placed: &[Placed], violations: &mut Vec<Violation>) {
+    let first = &placed[0];
+
+    // Below the cantus firmus, a fifth would put the counterpoint in another mode
+    let begins_well = if first.pitch.semitones_from_c0() < first.cantus.semitones_from_c0() {
+        first.is_unison_or_octave()
+    } else {
+        first.is_perfect_consonance()
+    };
+
+    if !begins_well {
+        violations.push(first.violation(SpeciesRule::Beginning));
+    }
+
+    let last = &placed[placed.len() - 1];
+
+    let is_stepwise =
+        placed.len() < 2 || steps_between(&placed[placed.len() - 2].pitch, &last.pitch).abs() == 1;
+
+    if !last.is_unison_or_octave() || !is_stepwise {
+        violations.push(last.violation(SpeciesRule::Ending));
+    }
+}
+
+fn check_melody(placed: &[Placed], violations: &mut Vec<Violation>) {
+    // Tied notes are a single note
+    let mut notes: Vec<&Placed> = vec![];
+
+    for current in placed {
+        if notes
+            .last()
+            .is_none_or(|previous| previous.pitch != current.pitch)
+        {
+            notes.push(current);
+        }
+    }
+
+    let highest = notes
+        .iter()
+        .map(|note| note.pitch.semitones_from_c0())
+        .max();
+
+    for note in notes
+        .iter()
+        .filter(|note| Some(note.pitch.semitones_from_c0()) == highest)
+        .skip(1)
+    {
+        violations.push(note.violation(SpeciesRule::RepeatedClimax));
+    }
+
+    for triple in notes.windows(3) {
+        let leap = steps_between(&triple[0].pitch, &triple[1].pitch);
+        let recovery = steps_between(&triple[1].pitch, &triple[2].pitch);
+
+        if leap.abs() >= 3 && leap.signum() == recovery.signum() {
+            violations.push(triple[2].violation(SpeciesRule::UnrecoveredLeap));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::counterpoint::{check_species, Species, SpeciesRule};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{Pitch, PitchClassUtils, PitchRootUtils};
+
+    fn cantus() -> Vec<Pitch> {
+        vec![D.o(4), F.o(4), E.o(4), D.o(4)]
+    }
+
+    fn rules(counterpoint: &[Vec<Pitch>], species: Species) -> Vec<(SpeciesRule, usize, usize)> {
+        check_species(&cantus(), counterpoint, species)
+            .into_iter()
+            .map(|violation| (violation.rule, violation.bar, violation.note))
+            .collect()
+    }
+
+    #[test]
+    fn passing_tones() {
+        let counterpoint = [
+            vec![A.o(4), B.o(4)],
+            vec![A.o(4), B.flat().o(4)],
+            vec![C.o(5), C.sharp().o(5)],
+            vec![D.o(5)],
+        ];
+
+        assert_eq!(rules(&counterpoint, Species::Second), vec![]);
+
+        let counterpoint = [
+            vec![A.o(4), B.o(4)],
+            vec![A.o(4), G.o(4)],
+            vec![C.o(5), C.sharp().o(5)],
+            vec![D.o(5)],
+        ];
+
+        assert_eq!(
+            rules(&counterpoint, Species::Second),
+            vec![(SpeciesRule::UntreatedDissonance, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn suspensions() {
+        let counterpoint = [
+            vec![A.o(4)],
+            vec![A.o(4), D.o(5)],
+            vec![D.o(5), C.o(5)],
+            vec![D.o(5)],
+        ];
+
+        let fourth = rules(&counterpoint, Species::Fourth);
+
+        assert!(!fourth.contains(&(SpeciesRule::DissonantDownbeat, 2, 0)));
+        assert!(!fourth.contains(&(SpeciesRule::UnresolvedSuspension, 2, 0)));
+        assert!(!fourth.contains(&(SpeciesRule::NotesPerBar, 0, 0)));
+
+        assert!(rules(&counterpoint, Species::Second).contains(&(
+            SpeciesRule::DissonantDownbeat,
+            2,
+            0
+        )));
+
+        let unresolved = [
+            vec![A.o(4)],
+            vec![A.o(4), D.o(5)],
+            vec![D.o(5), E.o(5)],
+            vec![D.o(5)],
+        ];
+
+        assert!(rules(&unresolved, Species::Fourth).contains(&(
+            SpeciesRule::UnresolvedSuspension,
+            2,
+            0
+        )));
+    }
+
+    #[test]
+    fn parallel_fifths() {
+        let cantus = [D.o(4), E.o(4), F.o(4)];
+        let counterpoint = [vec![A.o(4)], vec![B.o(4)], vec![C.o(5)]];
+
+        let violations = check_species(&cantus, &counterpoint, Species::First)
+            .into_iter()
+            .filter(|violation| violation.rule == SpeciesRule::ParallelPerfects)
+            .map(|violation| violation.bar)
+            .collect::<Vec<_>>();
+
+        assert_eq!(violations, vec![1, 2]);
+    }
+
+    #[test]
+    fn beginning_ending_and_climax() {
+        let counterpoint = [vec![F.o(4)], vec![D.o(5)], vec![G.o(4)], vec![D.o(5)]];
+
+        let violations = rules(&counterpoint, Species::First);
+
+        assert!(violations.contains(&(SpeciesRule::Beginning, 0, 0)));
+        assert!(violations.contains(&(SpeciesRule::Ending, 3, 0)));
+        assert!(violations.contains(&(SpeciesRule::RepeatedClimax, 3, 0)));
+    }
+
+    #[test]
+    fn unrecovered_leap() {
+        let counterpoint = [vec![A.o(4)], vec![D.o(4)], vec![C.o(4)], vec![D.o(5)]];
+
+        assert!(rules(&counterpoint, Species::First).contains(&(
+            SpeciesRule::UnrecoveredLeap,
+            2,
+            0
+        )));
+    }
+
+    #[test]
+    fn notes_per_bar() {
+        let counterpoint = [
+            vec![A.o(4), B.o(4), C.o(5), B.o(4)],
+            vec![A.o(4), G.o(4), A.o(4)],
+            vec![C.o(5), B.o(4), C.o(5), C.sharp().o(5)],
+            vec![D.o(5)],
+        ];
+
+        assert!(rules(&counterpoint, Species::Third).contains(&(SpeciesRule::NotesPerBar, 1, 0)));
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let counterpoint = [vec![A.o(4)], vec![A.o(4)], vec![C.o(5)]];
+
+        assert_eq!(
+            rules(&counterpoint, Species::First),
+            vec![(SpeciesRule::LengthMismatch, 3, 0)]
+        );
+    }
+}
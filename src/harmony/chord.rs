@@ -0,0 +1,229 @@
+use crate::harmony::ChordQuality;
+use crate::interval::{IntervalClass, IntervalClassUtils};
+use crate::pitch::{Octave, Pitch, PitchClass, PitchClassSet};
+use crate::vertical::TransposedBy;
+use std::fmt::{Display, Formatter};
+
+/// Which chord tone is in the bass.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Inversion {
+    Root,
+    First,
+    Second,
+    Third,
+}
+
+impl Inversion {
+    /// Returns the index of the bass in the stacked chord tones.
+    pub fn index(&self) -> usize {
+        match *self {
+            Self::Root => 0,
+            Self::First => 1,
+            Self::Second => 2,
+            Self::Third => 3,
+        }
+    }
+}
+
+impl TryFrom<usize> for Inversion {
+    type Error = &'static str;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        match index {
+            0 => Ok(Self::Root),
+            1 => Ok(Self::First),
+            2 => Ok(Self::Second),
+            3 => Ok(Self::Third),
+            _ => Err("Chords have at most three inversions"),
+        }
+    }
+}
+
+impl Display for Inversion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Root => "root position",
+            Self::First => "first inversion",
+            Self::Second => "second inversion",
+            Self::Third => "third inversion",
+        })
+    }
+}
+
+/// A [chord](https://en.wikipedia.org/wiki/Chord_(music)) built on a root, with a quality and an inversion.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::harmony::{Chord, ChordQuality, Inversion};
+/// use solfege::pitch::PitchRoot::{B, C, E, G};
+/// use solfege::pitch::{PitchClassUtils, PitchRootUtils};
+///
+/// let chord = Chord {
+///     root: C.sharp(),
+///     quality: ChordQuality::DiminishedSeventh,
+///     inversion: Inversion::First,
+/// };
+///
+/// assert_eq!(chord.tones(), vec![C.sharp(), E.natural(), G.natural(), B.flat()]);
+/// assert_eq!(chord.bass(), E.natural());
+/// assert_eq!(chord.pitches(3), vec![E.o(3), G.o(3), B.flat().o(3), C.sharp().o(4)]);
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Chord {
+    pub root: PitchClass,
+    pub quality: ChordQuality,
+    pub inversion: Inversion,
+}
+
+impl Chord {
+    /// Creates a chord in root position.
+    pub fn new(root: PitchClass, quality: ChordQuality) -> Self {
+        Chord {
+            root,
+            quality,
+            inversion: Inversion::Root,
+        }
+    }
+
+    /// Returns the chord tones stacked from the root.
+    pub fn tones(&self) -> Vec<PitchClass> {
+        self.quality
+            .intervals()
+            .iter()
+            .map(|interval| self.root.transposed_by(interval))
+            .collect()
+    }
+
+    pub fn pitch_classes(&self) -> PitchClassSet {
+        self.tones().into_iter().collect()
+    }
+
+    pub fn bass(&self) -> PitchClass {
+        let tones = self.tones();
+
+        assert!(
+            self.inversion.index() < tones.len(),
+            "The chord has too few tones for its inversion",
+        );
+
+        tones[self.inversion.index()]
+    }
+
+    /// Returns the chord tones in close position, starting with the bass in the given octave.
+    pub fn pitches(&self, bass_octave: i32) -> Vec<Pitch> {
+        let intervals = self.quality.intervals();
+
+        assert!(
+            self.inversion.index() < intervals.len(),
+            "The chord has too few tones for its inversion",
+        );
+
+        let mut pitches = vec![Pitch {
+            octave: Octave::from(bass_octave),
+            class: self.bass(),
+        }];
+
+        for offset in 1..intervals.len() {
+            let from = intervals[(self.inversion.index() + offset - 1) % intervals.len()];
+            let to = intervals[(self.inversion.index() + offset) % intervals.len()];
+
+            let previous = pitches[offset - 1];
+
+            pitches.push(previous.transposed_by(&(to - from).simple()));
+        }
+
+        pitches
+    }
+}
+
+impl TransposedBy<&IntervalClass> for Chord {
+    fn transposed_by(&self, delta: &IntervalClass) -> Self {
+        Chord {
+            root: self.root.transposed_by(delta),
+            quality: self.quality,
+            inversion: self.inversion,
+        }
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.inversion {
+            Inversion::Root => write!(f, "{} {}", self.root, self.quality),
+            inversion => write!(f, "{} {}, {}", self.root, self.quality, inversion),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{Chord, ChordQuality, Inversion};
+    use crate::interval::minor;
+    use crate::interval::IntervalRoot::Third;
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassUtils, PitchRootUtils};
+    use crate::vertical::TransposedBy;
+
+    #[test]
+    fn spelling() {
+        assert_eq!(
+            Chord::new(F.sharp(), ChordQuality::HalfDiminishedSeventh).tones(),
+            vec![F.sharp(), A.natural(), C.natural(), E.natural()],
+        );
+        assert_eq!(
+            Chord::new(A.flat(), ChordQuality::AugmentedMajorSeventh).tones(),
+            vec![A.flat(), C.natural(), E.natural(), G.natural()],
+        );
+        assert_eq!(
+            Chord::new(D.natural(), ChordQuality::MinorSixth).tones(),
+            vec![D.natural(), F.natural(), A.natural(), B.natural()],
+        );
+    }
+
+    #[test]
+    fn pitches() {
+        let chord = Chord {
+            root: G.natural(),
+            quality: ChordQuality::DominantSeventh,
+            inversion: Inversion::Third,
+        };
+
+        assert_eq!(chord.pitches(3), vec![F.o(3), G.o(3), B.o(3), D.o(4)]);
+
+        let chord = Chord {
+            root: B.flat(),
+            quality: ChordQuality::Major,
+            inversion: Inversion::Second,
+        };
+
+        assert_eq!(chord.pitches(4), vec![F.o(4), B.flat().o(4), D.o(5)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inversion_out_of_range() {
+        Chord {
+            root: C.natural(),
+            quality: ChordQuality::Major,
+            inversion: Inversion::Third,
+        }
+        .bass();
+    }
+
+    #[test]
+    fn transposition() {
+        assert_eq!(
+            Chord::new(E.natural(), ChordQuality::Minor).transposed_by(&minor(Third)),
+            Chord::new(G.natural(), ChordQuality::Minor),
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Chord::new(E.flat(), ChordQuality::MajorSeventh).to_string(),
+            "E♭ major seventh",
+        );
+    }
+}
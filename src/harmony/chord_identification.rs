@@ -0,0 +1,207 @@
+use crate::common::Scalar;
+use crate::harmony::{Chord, ChordSymbol, Inversion, CHORD_QUALITIES};
+use crate::interval::IntervalRoot::{Fifth, Fourth, Second, Sixth};
+use crate::interval::{perfect, IntervalClass};
+use crate::pitch::{PitchClass, PitchClassSet, PitchSet};
+use crate::vertical::TransposedBy;
+
+/// A possible reading of a collection of pitches as a chord.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ChordCandidate {
+    /// The chord name, including any added tones, omitted fifth and slash bass.
+    pub symbol: ChordSymbol,
+    /// The inversion of the chord, if the bass is known and is a chord tone.
+    pub inversion: Option<Inversion>,
+    /// How unlikely the reading is; lower is more plausible.
+    pub penalty: Scalar,
+}
+
+pub trait IdentifyChords {
+    /// Returns the chords that the pitches could be named as, most plausible first.
+    fn identify_chords(&self) -> Vec<ChordCandidate>;
+}
+
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{IdentifyChords, Inversion};
+/// use solfege::pitch::PitchRoot::{A, C, E, G};
+/// use solfege::pitch::{PitchClassUtils, PitchRootUtils, PitchSet};
+///
+/// let spelled_as_augmented = PitchSet::from([C.o(4), E.o(4), G.sharp().o(4)]);
+/// let spelled_from_a_flat = PitchSet::from([C.o(4), E.o(4), A.flat().o(4)]);
+///
+/// assert_eq!(spelled_as_augmented.identify_chords()[0].symbol.to_string(), "C+");
+///
+/// let candidate = &spelled_from_a_flat.identify_chords()[0];
+///
+/// assert_eq!(candidate.symbol.to_string(), "A♭+/C");
+/// assert_eq!(candidate.inversion, Some(Inversion::First));
+/// ```
+impl IdentifyChords for PitchSet {
+    fn identify_chords(&self) -> Vec<ChordCandidate> {
+        let classes = self.iter().map(|pitch| pitch.class).collect();
+
+        candidates(&classes, self.iter().next().map(|pitch| pitch.class))
+    }
+}
+
+/// Without a bass, every candidate is read in root position.
+impl IdentifyChords for PitchClassSet {
+    fn identify_chords(&self) -> Vec<ChordCandidate> {
+        candidates(self, None)
+    }
+}
+
+fn candidates(classes: &PitchClassSet, bass: Option<PitchClass>) -> Vec<ChordCandidate> {
+    let mut candidates = vec![];
+
+    for root in classes {
+        for (quality_index, quality) in CHORD_QUALITIES.iter().enumerate() {
+            let chord = Chord::new(*root, *quality);
+            let tones = chord.tones();
+
+            let fifth = root.transposed_by(&perfect(Fifth));
+
+            // Only the perfect fifth may be left out
+            let missing = tones
+                .iter()
+                .filter(|tone| !classes.contains(tone))
+                .collect::<Vec<_>>();
+
+            if missing.iter().any(|tone| **tone != fifth) {
+                continue;
+            }
+
+            // The slash bass is not counted as an added tone
+            let slash_bass = bass.filter(|bass| !tones.contains(bass));
+
+            let added = classes
+                .iter()
+                .filter(|class| !tones.contains(class) && Some(**class) != slash_bass)
+                .map(|class| IntervalClass::between(root, class))
+                .collect::<Vec<_>>();
+
+            if added
+                .iter()
+                .any(|interval| !matches!(interval.root, Second | Fourth | Sixth))
+            {
+                continue;
+            }
+
+            let inversion = bass.and_then(|bass| {
+                tones
+                    .iter()
+                    .position(|tone| *tone == bass)
+                    .map(|index| Inversion::try_from(index).expect("Chords have at most 4 tones"))
+            });
+
+            let mut symbol = ChordSymbol::new(*root, *quality);
+
+            symbol.added = added.clone();
+
+            if !missing.is_empty() {
+                symbol.omitted.push(Fifth);
+            }
+
+            if bass.is_some_and(|bass| bass != *root) {
+                symbol.bass = bass;
+            }
+
+            let penalty = 2 * missing.len() as Scalar
+                + 3 * added.len() as Scalar
+                + inversion.map_or(0, |inversion| inversion.index() as Scalar)
+                + if slash_bass.is_some() { 5 } else { 0 }
+                + if quality_index >= 4 { 1 } else { 0 };
+
+            candidates.push(ChordCandidate {
+                symbol,
+                inversion,
+                penalty,
+            });
+        }
+    }
+
+    candidates.sort_by_key(|candidate| candidate.penalty);
+
+    candidates
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{IdentifyChords, Inversion};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassSet, PitchClassUtils, PitchRootUtils, PitchSet};
+
+    fn best(set: PitchSet) -> String {
+        set.identify_chords()[0].symbol.to_string()
+    }
+
+    #[test]
+    fn triads_and_sevenths() {
+        assert_eq!(best(PitchSet::from([C.o(4), E.o(4), G.o(4)])), "C");
+        assert_eq!(best(PitchSet::from([G.o(2), B.o(3), D.o(4), F.o(4)])), "G7",);
+        assert_eq!(
+            best(PitchSet::from([
+                C.sharp().o(4),
+                E.o(4),
+                G.o(4),
+                B.flat().o(4),
+            ])),
+            "C♯dim7",
+        );
+    }
+
+    #[test]
+    fn inversions() {
+        let candidate = &PitchSet::from([F.o(3), G.o(3), B.o(3), D.o(4)]).identify_chords()[0];
+
+        assert_eq!(candidate.symbol.to_string(), "G7/F");
+        assert_eq!(candidate.inversion, Some(Inversion::Third));
+    }
+
+    #[test]
+    fn sixth_chords_and_their_relatives() {
+        assert_eq!(best(PitchSet::from([C.o(3), E.o(3), G.o(3), A.o(3)])), "C6",);
+        assert_eq!(
+            best(PitchSet::from([A.o(2), C.o(3), E.o(3), G.o(3)])),
+            "Am7",
+        );
+    }
+
+    #[test]
+    fn omitted_fifth() {
+        assert_eq!(
+            best(PitchSet::from([C.o(3), E.o(3), B.flat().o(3)])),
+            "C7no5",
+        );
+    }
+
+    #[test]
+    fn added_tones_and_slash_bass() {
+        assert_eq!(
+            best(PitchSet::from([C.o(4), D.o(4), E.o(4), G.o(4)])),
+            "Cadd9",
+        );
+        assert_eq!(
+            best(PitchSet::from([D.o(3), C.o(4), E.o(4), G.o(4)])),
+            "C/D",
+        );
+    }
+
+    #[test]
+    fn without_bass() {
+        let candidates =
+            PitchClassSet::from([E.natural(), G.natural(), C.natural()]).identify_chords();
+
+        assert_eq!(candidates[0].symbol.to_string(), "C");
+        assert_eq!(candidates[0].inversion, None);
+    }
+
+    #[test]
+    fn unrecognisable() {
+        assert!(PitchSet::from([C.o(4), C.sharp().o(4), D.o(4)])
+            .identify_chords()
+            .is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::interval::IntervalRoot::{Fifth, Fourth, Second, Seventh, Sixth, Third, Unison};
+use crate::interval::{augmented, diminished, major, minor, perfect, IntervalClass};
+use std::fmt::{Display, Formatter};
+
+/// The quality of a [chord](https://en.wikipedia.org/wiki/Chord_(music)), defining the intervals of its tones above the root.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum ChordQuality {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    Sus2,
+    Sus4,
+    DominantSeventh,
+    MajorSeventh,
+    MinorSeventh,
+    HalfDiminishedSeventh,
+    DiminishedSeventh,
+    MinorMajorSeventh,
+    AugmentedMajorSeventh,
+    MajorSixth,
+    MinorSixth,
+}
+
+/// All chord qualities, triads first.
+pub const CHORD_QUALITIES: [ChordQuality; 15] = [
+    ChordQuality::Major,
+    ChordQuality::Minor,
+    ChordQuality::Diminished,
+    ChordQuality::Augmented,
+    ChordQuality::Sus2,
+    ChordQuality::Sus4,
+    ChordQuality::DominantSeventh,
+    ChordQuality::MajorSeventh,
+    ChordQuality::MinorSeventh,
+    ChordQuality::HalfDiminishedSeventh,
+    ChordQuality::DiminishedSeventh,
+    ChordQuality::MinorMajorSeventh,
+    ChordQuality::AugmentedMajorSeventh,
+    ChordQuality::MajorSixth,
+    ChordQuality::MinorSixth,
+];
+
+impl ChordQuality {
+    /// Returns the intervals of the chord tones above the root, in the order in which they are stacked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::harmony::ChordQuality;
+    /// use solfege::interval::IntervalRoot::{Fifth, Seventh, Third, Unison};
+    /// use solfege::interval::{diminished, minor, perfect};
+    ///
+    /// assert_eq!(
+    ///     ChordQuality::DiminishedSeventh.intervals(),
+    ///     vec![perfect(Unison), minor(Third), diminished(Fifth), diminished(Seventh)],
+    /// );
+    /// ```
+    pub fn intervals(&self) -> Vec<IntervalClass> {
+        let mut intervals = vec![perfect(Unison)];
+
+        intervals.extend(match *self {
+            Self::Major => vec![major(Third), perfect(Fifth)],
+            Self::Minor => vec![minor(Third), perfect(Fifth)],
+            Self::Diminished => vec![minor(Third), diminished(Fifth)],
+            Self::Augmented => vec![major(Third), augmented(Fifth)],
+            Self::Sus2 => vec![major(Second), perfect(Fifth)],
+            Self::Sus4 => vec![perfect(Fourth), perfect(Fifth)],
+            Self::DominantSeventh => vec![major(Third), perfect(Fifth), minor(Seventh)],
+            Self::MajorSeventh => vec![major(Third), perfect(Fifth), major(Seventh)],
+            Self::MinorSeventh => vec![minor(Third), perfect(Fifth), minor(Seventh)],
+            Self::HalfDiminishedSeventh => vec![minor(Third), diminished(Fifth), minor(Seventh)],
+            Self::DiminishedSeventh => vec![minor(Third), diminished(Fifth), diminished(Seventh)],
+            Self::MinorMajorSeventh => vec![minor(Third), perfect(Fifth), major(Seventh)],
+            Self::AugmentedMajorSeventh => vec![major(Third), augmented(Fifth), major(Seventh)],
+            Self::MajorSixth => vec![major(Third), perfect(Fifth), major(Sixth)],
+            Self::MinorSixth => vec![minor(Third), perfect(Fifth), major(Sixth)],
+        });
+
+        intervals
+    }
+}
+
+impl Display for ChordQuality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Diminished => "diminished",
+            Self::Augmented => "augmented",
+            Self::Sus2 => "suspended second",
+            Self::Sus4 => "suspended fourth",
+            Self::DominantSeventh => "dominant seventh",
+            Self::MajorSeventh => "major seventh",
+            Self::MinorSeventh => "minor seventh",
+            Self::HalfDiminishedSeventh => "half-diminished seventh",
+            Self::DiminishedSeventh => "diminished seventh",
+            Self::MinorMajorSeventh => "minor-major seventh",
+            Self::AugmentedMajorSeventh => "augmented major seventh",
+            Self::MajorSixth => "major sixth",
+            Self::MinorSixth => "minor sixth",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CHORD_QUALITIES;
+    use crate::vertical::InSemitones;
+
+    #[test]
+    fn intervals_ascend() {
+        for quality in CHORD_QUALITIES {
+            let intervals = quality.intervals();
+
+            assert!(intervals
+                .windows(2)
+                .all(|pair| pair[0].in_semitones() < pair[1].in_semitones()));
+        }
+    }
+}
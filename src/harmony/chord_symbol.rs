@@ -0,0 +1,759 @@
+use crate::harmony::parser::Parser;
+use crate::harmony::{Chord, ChordQuality};
+use crate::interval::IntervalRoot::{Fifth, Fourth, Second, Sixth, Third};
+use crate::interval::{major, minor, perfect, IntervalClass, IntervalRoot};
+use crate::pitch::{Accidental, PitchClass, PitchClassSet, PitchRoot};
+use crate::vertical::{InSemitones, Semitones, TransposedBy};
+use std::fmt::{Display, Formatter};
+
+/// The highest extension of a chord beyond its seventh.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Extension {
+    Ninth,
+    Eleventh,
+    Thirteenth,
+}
+
+impl Extension {
+    fn number(&self) -> &'static str {
+        match *self {
+            Self::Ninth => "9",
+            Self::Eleventh => "11",
+            Self::Thirteenth => "13",
+        }
+    }
+}
+
+/// The conventions used when writing a chord symbol.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ChordSymbolStyle {
+    /// Written before the number of major seventh chords, such as "maj" or "Δ".
+    pub major: &'static str,
+    /// Written for minor chords, such as "m" or "-".
+    pub minor: &'static str,
+    /// Written for diminished chords, such as "dim" or "°".
+    pub diminished: &'static str,
+    /// Written for augmented chords, such as "aug" or "+".
+    pub augmented: &'static str,
+    /// Whether half-diminished chords are written as "ø7" rather than "m7♭5".
+    pub half_diminished_symbol: bool,
+    /// Whether accidentals are written as "b" and "#" rather than "♭" and "♯".
+    pub ascii: bool,
+}
+
+impl ChordSymbolStyle {
+    /// Cmaj7, Cm7, Cm7♭5, Cdim7, C+
+    pub const STANDARD: ChordSymbolStyle = ChordSymbolStyle {
+        major: "maj",
+        minor: "m",
+        diminished: "dim",
+        augmented: "+",
+        half_diminished_symbol: false,
+        ascii: false,
+    };
+
+    /// CΔ7, C-7, Cø7, C°7, C+
+    pub const JAZZ: ChordSymbolStyle = ChordSymbolStyle {
+        major: "Δ",
+        minor: "-",
+        diminished: "°",
+        augmented: "+",
+        half_diminished_symbol: true,
+        ascii: false,
+    };
+
+    /// Cmaj7, Cm7, Cm7b5, Cdim7, Caug
+    pub const ASCII: ChordSymbolStyle = ChordSymbolStyle {
+        major: "maj",
+        minor: "m",
+        diminished: "dim",
+        augmented: "aug",
+        half_diminished_symbol: false,
+        ascii: true,
+    };
+}
+
+/// A lead-sheet [chord symbol](https://en.wikipedia.org/wiki/Chord_names_and_symbols_(popular_music)),
+/// such as "Cmaj7", "F♯m7♭5" or "B♭7(♯9,♭13)/D".
+///
+/// Both ASCII and Unicode accidentals are accepted when parsing.
+/// A major seventh sign without a number, as in "CΔ", is read as a major seventh chord.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::harmony::{ChordSymbol, ChordSymbolStyle};
+/// use solfege::pitch::PitchRoot::{A, B, C, D, F, G};
+/// use solfege::pitch::{PitchClassSet, PitchRootUtils};
+///
+/// let symbol = ChordSymbol::try_from("Bb7(#9,b13)/D").unwrap();
+///
+/// assert_eq!(
+///     symbol.pitch_classes(),
+///     PitchClassSet::from([B.flat(), D.natural(), F.natural(), A.flat(), C.sharp(), G.flat()]),
+/// );
+/// assert_eq!(symbol.bass(), D.natural());
+/// assert_eq!(symbol.to_string(), "B♭7(♯9,♭13)/D");
+/// assert_eq!(symbol.render(&ChordSymbolStyle::ASCII), "Bb7(#9,b13)/D");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ChordSymbol {
+    pub root: PitchClass,
+    pub quality: ChordQuality,
+    pub extension: Option<Extension>,
+    /// Replaces the third of the chord with a second or fourth.
+    pub suspension: Option<IntervalClass>,
+    /// Altered fifths, ninths, elevenths and thirteenths, which replace their unaltered versions.
+    pub alterations: Vec<IntervalClass>,
+    pub added: Vec<IntervalClass>,
+    pub omitted: Vec<IntervalRoot>,
+    pub bass: Option<PitchClass>,
+}
+
+impl ChordSymbol {
+    /// Creates a chord symbol without extensions, alterations or a bass note.
+    pub fn new(root: PitchClass, quality: ChordQuality) -> Self {
+        ChordSymbol {
+            root,
+            quality,
+            extension: None,
+            suspension: None,
+            alterations: vec![],
+            added: vec![],
+            omitted: vec![],
+            bass: None,
+        }
+    }
+
+    /// Returns the intervals of all chord tones above the root.
+    pub fn intervals(&self) -> Vec<IntervalClass> {
+        let mut intervals = self.quality.intervals();
+
+        let has_minor_third = intervals.contains(&minor(Third));
+
+        intervals.extend(match self.extension {
+            None => vec![],
+            Some(Extension::Ninth) => vec![major(Second)],
+            Some(Extension::Eleventh) => vec![major(Second), perfect(Fourth)],
+            Some(Extension::Thirteenth) if has_minor_third => {
+                vec![major(Second), perfect(Fourth), major(Sixth)]
+            }
+            Some(Extension::Thirteenth) => vec![major(Second), major(Sixth)],
+        });
+
+        if let Some(suspension) = self.suspension {
+            intervals.retain(|interval| interval.root != Third);
+            intervals.push(suspension);
+        }
+
+        for alteration in &self.alterations {
+            intervals.retain(|interval| {
+                interval.root != alteration.root || self.alterations.contains(interval)
+            });
+
+            if !intervals.contains(alteration) {
+                intervals.push(*alteration);
+            }
+        }
+
+        for added in &self.added {
+            if !intervals.contains(added) {
+                intervals.push(*added);
+            }
+        }
+
+        intervals.retain(|interval| !self.omitted.contains(&interval.root));
+
+        intervals
+    }
+
+    pub fn pitch_classes(&self) -> PitchClassSet {
+        self.intervals()
+            .iter()
+            .map(|interval| self.root.transposed_by(interval))
+            .collect()
+    }
+
+    /// Returns the slash bass, or the root if there is none.
+    pub fn bass(&self) -> PitchClass {
+        self.bass.unwrap_or(self.root)
+    }
+
+    /// Writes the chord symbol in the given style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::harmony::{ChordSymbol, ChordSymbolStyle};
+    ///
+    /// let symbol = ChordSymbol::try_from("F#m7b5").unwrap();
+    ///
+    /// assert_eq!(symbol.render(&ChordSymbolStyle::STANDARD), "F♯m7♭5");
+    /// assert_eq!(symbol.render(&ChordSymbolStyle::JAZZ), "F♯ø7");
+    /// ```
+    pub fn render(&self, style: &ChordSymbolStyle) -> String {
+        let number = match self.extension {
+            Some(extension) => extension.number(),
+            None => "7",
+        };
+
+        let sixth = if self.added.contains(&major(Second)) {
+            "6/9"
+        } else {
+            "6"
+        };
+
+        let quality = match self.quality {
+            ChordQuality::Major => String::new(),
+            ChordQuality::Minor => style.minor.to_string(),
+            ChordQuality::Diminished => style.diminished.to_string(),
+            ChordQuality::Augmented => style.augmented.to_string(),
+            ChordQuality::Sus2 => String::from("sus2"),
+            ChordQuality::Sus4 => String::from("sus4"),
+            ChordQuality::DominantSeventh => number.to_string(),
+            ChordQuality::MajorSeventh => format!("{}{}", style.major, number),
+            ChordQuality::MinorSeventh => format!("{}{}", style.minor, number),
+            ChordQuality::HalfDiminishedSeventh if style.half_diminished_symbol => {
+                format!("ø{}", number)
+            }
+            ChordQuality::HalfDiminishedSeventh => format!(
+                "{}{}{}5",
+                style.minor,
+                number,
+                accidental(Semitones(-1), style)
+            ),
+            ChordQuality::DiminishedSeventh => format!("{}{}", style.diminished, number),
+            ChordQuality::MinorMajorSeventh => format!("{}{}{}", style.minor, style.major, number),
+            ChordQuality::AugmentedMajorSeventh => {
+                format!("{}{}{}", style.augmented, style.major, number)
+            }
+            ChordQuality::MajorSixth => sixth.to_string(),
+            ChordQuality::MinorSixth => format!("{}{}", style.minor, sixth),
+        };
+
+        let mut string = format!("{}{}", pitch_class(&self.root, style), quality);
+
+        if let Some(suspension) = self.suspension {
+            string += &format!("sus{}", suspension.root);
+        }
+
+        let alterations = self
+            .alterations
+            .iter()
+            .map(|alteration| {
+                format!(
+                    "{}{}",
+                    accidental(alteration.semitones - alteration.root.in_semitones(), style),
+                    degree(alteration.root)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        match alterations.len() {
+            0 => {}
+            1 => string += &alterations[0],
+            _ => string += &format!("({})", alterations.join(",")),
+        }
+
+        for added in &self.added {
+            let is_six_nine = *added == major(Second)
+                && matches!(
+                    self.quality,
+                    ChordQuality::MajorSixth | ChordQuality::MinorSixth
+                );
+
+            if !is_six_nine {
+                string += &format!(
+                    "add{}{}",
+                    accidental(added.semitones - added.root.in_semitones(), style),
+                    degree(added.root)
+                );
+            }
+        }
+
+        for omitted in &self.omitted {
+            string += &format!("no{}", omitted);
+        }
+
+        if let Some(bass) = self.bass {
+            string += &format!("/{}", pitch_class(&bass, style));
+        }
+
+        string
+    }
+}
+
+impl From<&Chord> for ChordSymbol {
+    fn from(chord: &Chord) -> Self {
+        let mut symbol = ChordSymbol::new(chord.root, chord.quality);
+
+        if chord.bass() != chord.root {
+            symbol.bass = Some(chord.bass());
+        }
+
+        symbol
+    }
+}
+
+impl Display for ChordSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(&ChordSymbolStyle::STANDARD))
+    }
+}
+
+impl TryFrom<&str> for ChordSymbol {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parser = Parser::new(value);
+
+        let root = parser.pitch_class()?;
+
+        let mut symbol = ChordSymbol::new(root, ChordQuality::Major);
+
+        parser.quality(&mut symbol)?;
+
+        let mut parenthesised = false;
+
+        while !parser.rest.is_empty() && !parser.rest.starts_with('/') {
+            if parser.eat("(") {
+                if parenthesised {
+                    return Err("Nested parentheses in chord symbol");
+                }
+
+                parenthesised = true;
+            } else if parser.eat(")") {
+                if !parenthesised {
+                    return Err("Unmatched closing parenthesis in chord symbol");
+                }
+
+                parenthesised = false;
+            } else {
+                parser.modifier(&mut symbol)?;
+            }
+        }
+
+        if parenthesised {
+            return Err("Unclosed parenthesis in chord symbol");
+        }
+
+        if parser.eat("/") {
+            symbol.bass = Some(parser.pitch_class()?);
+        }
+
+        if !parser.rest.is_empty() {
+            return Err("Could not parse the end of the chord symbol");
+        }
+
+        Ok(symbol)
+    }
+}
+
+fn pitch_class(class: &PitchClass, style: &ChordSymbolStyle) -> String {
+    format!(
+        "{}{}",
+        class.root,
+        accidental(class.accidental.offset, style)
+    )
+}
+
+fn accidental(offset: Semitones, style: &ChordSymbolStyle) -> String {
+    match (offset.0, style.ascii) {
+        (0, _) => String::new(),
+        (offset, true) if offset < 0 => String::from('b').repeat(-offset as usize),
+        (offset, true) => String::from('#').repeat(offset as usize),
+        (offset, false) => Accidental {
+            offset: Semitones(offset),
+        }
+        .to_string(),
+    }
+}
+
+/// Returns the number a chord tone is written with, using compound numbers for the tensions.
+fn degree(root: IntervalRoot) -> &'static str {
+    match root {
+        IntervalRoot::Unison => "1",
+        IntervalRoot::Second => "9",
+        IntervalRoot::Third => "3",
+        IntervalRoot::Fourth => "11",
+        IntervalRoot::Fifth => "5",
+        IntervalRoot::Sixth => "13",
+        IntervalRoot::Seventh => "7",
+    }
+}
+
+impl Parser<'_> {
+    fn pitch_class(&mut self) -> Result<PitchClass, &'static str> {
+        let root = PitchRoot::try_from(self.rest.get(0..1).ok_or("Missing pitch class")?)?;
+
+        self.rest = &self.rest[1..];
+
+        Ok(PitchClass {
+            root,
+            accidental: self.accidental(),
+        })
+    }
+
+    fn extension(&mut self) -> Option<Option<Extension>> {
+        if self.eat("13") {
+            Some(Some(Extension::Thirteenth))
+        } else if self.eat("11") {
+            Some(Some(Extension::Eleventh))
+        } else if self.eat("9") {
+            Some(Some(Extension::Ninth))
+        } else if self.eat("7") {
+            Some(None)
+        } else {
+            None
+        }
+    }
+
+    fn quality(&mut self, symbol: &mut ChordSymbol) -> Result<(), &'static str> {
+        let major = ["maj", "Maj", "ma", "M", "Δ"];
+
+        if self.eat_any(&["ø", "Ø"]) {
+            symbol.quality = ChordQuality::HalfDiminishedSeventh;
+            symbol.extension = self.extension().flatten();
+        } else if !self.rest.starts_with("omit") && self.eat_any(&["dim", "°", "o"]) {
+            symbol.quality = match self.extension() {
+                Some(extension) => {
+                    symbol.extension = extension;
+                    ChordQuality::DiminishedSeventh
+                }
+                None => ChordQuality::Diminished,
+            };
+        } else if self.eat_any(&["aug", "+"]) {
+            symbol.quality = if self.eat_any(&major) {
+                symbol.extension = self.extension().flatten();
+                ChordQuality::AugmentedMajorSeventh
+            } else {
+                ChordQuality::Augmented
+            };
+        } else if self.eat_any(&major) {
+            symbol.quality = ChordQuality::MajorSeventh;
+            symbol.extension = self.extension().flatten();
+        } else if self.eat_any(&["min", "mi", "m", "-"]) {
+            let parenthesised = self.eat("(");
+
+            symbol.quality = if self.eat_any(&major) {
+                symbol.extension = self.extension().flatten();
+                ChordQuality::MinorMajorSeventh
+            } else if let Some(extension) = self.extension() {
+                symbol.extension = extension;
+                ChordQuality::MinorSeventh
+            } else if self.eat("6") {
+                self.six_nine(symbol);
+                ChordQuality::MinorSixth
+            } else {
+                ChordQuality::Minor
+            };
+
+            if parenthesised && !self.eat(")") {
+                return Err("Unclosed parenthesis in chord quality");
+            }
+
+            if symbol.quality == ChordQuality::MinorSeventh && self.eat_any(&["♭5", "b5"]) {
+                symbol.quality = ChordQuality::HalfDiminishedSeventh;
+            }
+        } else if self.eat("6") {
+            self.six_nine(symbol);
+            symbol.quality = ChordQuality::MajorSixth;
+        } else if let Some(extension) = self.extension() {
+            symbol.quality = ChordQuality::DominantSeventh;
+            symbol.extension = extension;
+        } else if self.eat("sus2") {
+            symbol.quality = ChordQuality::Sus2;
+        } else if self.eat_any(&["sus4", "sus"]) {
+            symbol.quality = ChordQuality::Sus4;
+        }
+
+        Ok(())
+    }
+
+    fn six_nine(&mut self, symbol: &mut ChordSymbol) {
+        if self.eat_any(&["/9", "9"]) {
+            symbol.added.push(major(Second));
+        }
+    }
+
+    fn modifier(&mut self, symbol: &mut ChordSymbol) -> Result<(), &'static str> {
+        if self.eat_any(&[",", " "]) {
+            return Ok(());
+        }
+
+        if self.eat("sus2") {
+            symbol.suspension = Some(major(Second));
+        } else if self.eat_any(&["sus4", "sus"]) {
+            symbol.suspension = Some(perfect(Fourth));
+        } else if self.eat("add") {
+            let offset = self.accidental().offset;
+            let root = self.degree()?;
+
+            symbol.added.push(altered(root, offset));
+        } else if self.eat_any(&["no", "omit"]) {
+            match self.degree()? {
+                root @ (Third | Fifth) => symbol.omitted.push(root),
+                _ => return Err("Only the third and fifth can be omitted"),
+            }
+        } else {
+            let offset = self.accidental().offset;
+
+            if offset == Semitones(0) {
+                return Err("Could not parse chord modifier");
+            }
+
+            let root = self.degree()?;
+
+            if !matches!(root, Second | Fourth | Fifth | Sixth) {
+                return Err("Only fifths, ninths, elevenths and thirteenths can be altered");
+            }
+
+            symbol.alterations.push(altered(root, offset));
+        }
+
+        Ok(())
+    }
+
+    fn degree(&mut self) -> Result<IntervalRoot, &'static str> {
+        for (number, root) in [
+            ("13", Sixth),
+            ("11", Fourth),
+            ("9", Second),
+            ("2", Second),
+            ("3", Third),
+            ("4", Fourth),
+            ("5", Fifth),
+            ("6", Sixth),
+        ] {
+            if self.eat(number) {
+                return Ok(root);
+            }
+        }
+
+        Err("Could not parse chord degree")
+    }
+}
+
+/// Returns the interval on a root, altered from its perfect or major version by an offset.
+fn altered(root: IntervalRoot, offset: Semitones) -> IntervalClass {
+    IntervalClass {
+        root,
+        semitones: root.in_semitones() + offset,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{
+        Chord, ChordQuality, ChordSymbol, ChordSymbolStyle, Extension, Inversion,
+    };
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassSet, PitchRootUtils};
+    use crate::vertical::{Semitones, TransposedBy};
+
+    fn symbol(value: &str) -> ChordSymbol {
+        ChordSymbol::try_from(value).unwrap()
+    }
+
+    fn classes(value: &str) -> PitchClassSet {
+        symbol(value).pitch_classes()
+    }
+
+    #[test]
+    fn qualities() {
+        assert_eq!(symbol("C").quality, ChordQuality::Major);
+        assert_eq!(symbol("Cm").quality, ChordQuality::Minor);
+        assert_eq!(symbol("C-").quality, ChordQuality::Minor);
+        assert_eq!(symbol("Cdim").quality, ChordQuality::Diminished);
+        assert_eq!(symbol("C°7").quality, ChordQuality::DiminishedSeventh);
+        assert_eq!(symbol("C+").quality, ChordQuality::Augmented);
+        assert_eq!(symbol("Csus").quality, ChordQuality::Sus4);
+        assert_eq!(symbol("Csus2").quality, ChordQuality::Sus2);
+        assert_eq!(symbol("C7").quality, ChordQuality::DominantSeventh);
+        assert_eq!(symbol("Cmaj7").quality, ChordQuality::MajorSeventh);
+        assert_eq!(symbol("CΔ7").quality, ChordQuality::MajorSeventh);
+        assert_eq!(symbol("Cm7").quality, ChordQuality::MinorSeventh);
+        assert_eq!(symbol("Cm7♭5").quality, ChordQuality::HalfDiminishedSeventh);
+        assert_eq!(symbol("Cø").quality, ChordQuality::HalfDiminishedSeventh);
+        assert_eq!(symbol("Cm(maj7)").quality, ChordQuality::MinorMajorSeventh);
+        assert_eq!(symbol("C-Δ7").quality, ChordQuality::MinorMajorSeventh);
+        assert_eq!(
+            symbol("C+maj7").quality,
+            ChordQuality::AugmentedMajorSeventh
+        );
+        assert_eq!(symbol("C6").quality, ChordQuality::MajorSixth);
+        assert_eq!(symbol("Cm6").quality, ChordQuality::MinorSixth);
+    }
+
+    #[test]
+    fn spelling() {
+        assert_eq!(
+            classes("F♯m7♭5"),
+            PitchClassSet::from([F.sharp(), A.natural(), C.natural(), E.natural()]),
+        );
+        assert_eq!(
+            classes("Cmaj7"),
+            PitchClassSet::from([C.natural(), E.natural(), G.natural(), B.natural()]),
+        );
+        assert_eq!(
+            classes("Ebm9"),
+            PitchClassSet::from([E.flat(), G.flat(), B.flat(), D.flat(), F.natural()]),
+        );
+    }
+
+    #[test]
+    fn extensions() {
+        assert_eq!(symbol("G13").extension, Some(Extension::Thirteenth));
+        assert_eq!(
+            classes("G13"),
+            PitchClassSet::from([
+                G.natural(),
+                B.natural(),
+                D.natural(),
+                F.natural(),
+                A.natural(),
+                E.natural(),
+            ]),
+        );
+        assert_eq!(classes("Dm11").len(), 6);
+    }
+
+    #[test]
+    fn alterations() {
+        assert_eq!(
+            classes("C7♯5♭9"),
+            PitchClassSet::from([C.natural(), E.natural(), G.sharp(), B.flat(), D.flat()]),
+        );
+        assert_eq!(
+            classes("C7(b9,#9)"),
+            PitchClassSet::from([
+                C.natural(),
+                E.natural(),
+                G.natural(),
+                B.flat(),
+                D.flat(),
+                D.sharp(),
+            ]),
+        );
+        assert_eq!(
+            classes("Cmaj9#11"),
+            PitchClassSet::from([
+                C.natural(),
+                E.natural(),
+                G.natural(),
+                B.natural(),
+                D.natural(),
+                F.sharp(),
+            ]),
+        );
+    }
+
+    #[test]
+    fn added_and_omitted() {
+        assert_eq!(
+            classes("Cadd9"),
+            PitchClassSet::from([C.natural(), E.natural(), G.natural(), D.natural()]),
+        );
+        assert_eq!(
+            classes("C7no3"),
+            PitchClassSet::from([C.natural(), G.natural(), B.flat()]),
+        );
+        assert_eq!(
+            classes("C6/9"),
+            PitchClassSet::from([
+                C.natural(),
+                E.natural(),
+                G.natural(),
+                A.natural(),
+                D.natural(),
+            ]),
+        );
+    }
+
+    #[test]
+    fn diminished_extensions() {
+        let symbol = symbol("Cdim9");
+
+        assert_eq!(symbol.quality, ChordQuality::DiminishedSeventh);
+        assert_eq!(symbol.extension, Some(Extension::Ninth));
+        assert_eq!(
+            symbol.pitch_classes(),
+            PitchClassSet::from([
+                C.natural(),
+                E.flat(),
+                G.flat(),
+                B.flat().transposed_by(Semitones(-1)),
+                D.natural(),
+            ]),
+        );
+        assert_eq!(symbol.to_string(), "Cdim9");
+    }
+
+    #[test]
+    fn suspensions() {
+        assert_eq!(
+            classes("G7sus4"),
+            PitchClassSet::from([G.natural(), C.natural(), D.natural(), F.natural()]),
+        );
+    }
+
+    #[test]
+    fn slash_bass() {
+        assert_eq!(symbol("C/E").bass(), E.natural());
+        assert_eq!(symbol("C").bass(), C.natural());
+        assert_eq!(symbol("Am7/G").bass, Some(G.natural()));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(ChordSymbol::try_from("H7").is_err());
+        assert!(ChordSymbol::try_from("C7(♯9").is_err());
+        assert!(ChordSymbol::try_from("C7)))").is_err());
+        assert!(ChordSymbol::try_from("C7((♯9))").is_err());
+        assert!(ChordSymbol::try_from("C7♯3").is_err());
+        assert!(ChordSymbol::try_from("Cxyz").is_err());
+    }
+
+    #[test]
+    fn rendering() {
+        for value in [
+            "C",
+            "Cm",
+            "Cdim",
+            "C+",
+            "Csus4",
+            "C7",
+            "Cmaj7",
+            "F♯m7♭5",
+            "Cdim7",
+            "Cmmaj7",
+            "C+maj7",
+            "C6/9",
+            "Cm6",
+            "G13",
+            "D♭maj9",
+            "C7♯5",
+            "B♭7(♯9,♭13)/D",
+            "G7sus4",
+            "Cadd9",
+            "C7no3",
+        ] {
+            assert_eq!(symbol(value).to_string(), value);
+        }
+
+        assert_eq!(symbol("Cmaj7").render(&ChordSymbolStyle::JAZZ), "CΔ7",);
+        assert_eq!(symbol("Cm7").render(&ChordSymbolStyle::JAZZ), "C-7");
+        assert_eq!(symbol("C#dim7").render(&ChordSymbolStyle::ASCII), "C#dim7");
+    }
+
+    #[test]
+    fn from_chord() {
+        let chord = Chord {
+            root: E.flat(),
+            quality: ChordQuality::MinorSeventh,
+            inversion: Inversion::Third,
+        };
+
+        assert_eq!(ChordSymbol::from(&chord).to_string(), "E♭m7/D♭");
+    }
+}
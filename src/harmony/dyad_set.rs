@@ -1,5 +1,4 @@
 use crate::harmony::Dyad;
 use std::collections::btree_set::BTreeSet;
 
-#[allow(dead_code)]
 pub type DyadSet<T> = BTreeSet<Dyad<T>>;
@@ -0,0 +1,578 @@
+use crate::common::Scalar;
+use crate::key::Key;
+use crate::pitch::PitchRoot::{C, D, E, G};
+use crate::pitch::{
+    Accidental, InRange, Pitch, PitchClass, PitchClassSet, PitchClassUtils, PitchDyad, PitchSet,
+};
+use crate::vertical::{SemitonesFromC0, Steps, StepsFromC0, TransposedBy};
+use std::fmt::{Display, Formatter};
+
+/// A single [figure](https://en.wikipedia.org/wiki/Figured_bass): the number of an interval above the bass,
+/// with an accidental when the note differs from the key signature.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Figure {
+    pub number: Scalar,
+    pub accidental: Option<Accidental>,
+}
+
+impl Display for Figure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.number, self.accidental) {
+            // An accidental on its own applies to the third
+            (3, Some(accidental)) => write!(f, "{}", accidental),
+            (number, Some(accidental)) => write!(f, "{}{}", accidental, number),
+            (number, None) => write!(f, "{}", number),
+        }
+    }
+}
+
+impl TryFrom<&str> for Figure {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let index = value.find(char::is_numeric).unwrap_or(value.len());
+
+        let (accidental, number) = value.split_at(index);
+
+        let accidental = match accidental {
+            "" => None,
+            accidental => Some(Accidental::try_from(accidental)?),
+        };
+
+        let number = match number {
+            "" if accidental.is_some() => 3,
+            number => number.parse().map_err(|_| "Could not parse figure")?,
+        };
+
+        Ok(Figure { number, accidental })
+    }
+}
+
+/// The figures below a bass note, from top to bottom, as they would be written.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::harmony::FiguredBass;
+/// use solfege::key::Key;
+/// use solfege::pitch::PitchRoot::{B, D, F, G};
+/// use solfege::pitch::{PitchClassUtils, PitchSet};
+///
+/// let key = Key::try_from("C major").unwrap();
+///
+/// // V⁴₂ in C major
+/// let pitches = PitchSet::from([F.o(3), B.o(3), D.o(4), G.o(4)]);
+///
+/// assert_eq!(FiguredBass::of(&pitches, &key).to_string(), "4/2");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FiguredBass {
+    pub figures: Vec<Figure>,
+}
+
+/// The full sets of figures of common chords, with how they are abbreviated.
+/// The first abbreviation is the one that is written, and the others are only read.
+const ABBREVIATIONS: [(&[Scalar], &[&[Scalar]]); 7] = [
+    (&[5, 3], &[&[]]),
+    (&[6, 3], &[&[6]]),
+    (&[6, 4], &[&[6, 4]]),
+    (&[7, 5, 3], &[&[7]]),
+    (&[6, 5, 3], &[&[6, 5]]),
+    (&[6, 4, 3], &[&[4, 3]]),
+    (&[6, 4, 2], &[&[4, 2], &[2]]),
+];
+
+impl FiguredBass {
+    /// Returns the figures of a vertical sonority above its lowest pitch.
+    pub fn of(pitches: &PitchSet, key: &Key) -> Self {
+        let bass = match pitches.iter().next() {
+            Some(bass) => bass,
+            None => return FiguredBass { figures: vec![] },
+        };
+
+        let signature = key.signature();
+
+        let mut complete: Vec<Figure> = vec![];
+
+        for pitch in pitches.iter().skip(1) {
+            let steps = (pitch.steps_from_c0() - bass.steps_from_c0()).0 % 7;
+
+            // Doublings of the bass are not figured
+            if steps == 0 && pitch.class == bass.class {
+                continue;
+            }
+
+            let number = if steps == 0 { 8 } else { steps + 1 };
+
+            let accidental = (pitch.class.accidental != signature.accidental(pitch.class.root))
+                .then_some(pitch.class.accidental);
+
+            if !complete.iter().any(|figure| figure.number == number) {
+                complete.push(Figure { number, accidental });
+            }
+        }
+
+        complete.sort_by_key(|figure| std::cmp::Reverse(figure.number));
+
+        let numbers = complete
+            .iter()
+            .map(|figure| figure.number)
+            .collect::<Vec<_>>();
+
+        let written = ABBREVIATIONS
+            .iter()
+            .find(|(full, _)| *full == numbers.as_slice())
+            .map(|(_, abbreviations)| abbreviations[0].to_vec())
+            .unwrap_or(numbers);
+
+        // Figures with accidentals are always written, even when the abbreviation leaves them out
+        let figures = complete
+            .into_iter()
+            .filter(|figure| written.contains(&figure.number) || figure.accidental.is_some())
+            .collect();
+
+        FiguredBass { figures }
+    }
+
+    /// Returns the pitch classes above a bass note that the figures stand for in a key,
+    /// filling in the figures that the abbreviation leaves out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::harmony::FiguredBass;
+    /// use solfege::key::Key;
+    /// use solfege::pitch::PitchRoot::{A, C, E};
+    /// use solfege::pitch::{PitchClassSet, PitchRootUtils};
+    ///
+    /// let key = Key::try_from("D minor").unwrap();
+    ///
+    /// assert_eq!(
+    ///     FiguredBass::try_from("♯").unwrap().pitch_classes_above(&A.natural(), &key),
+    ///     PitchClassSet::from([C.sharp(), E.natural()]),
+    /// );
+    /// ```
+    pub fn pitch_classes_above(&self, bass: &PitchClass, key: &Key) -> PitchClassSet {
+        let written = self
+            .figures
+            .iter()
+            .map(|figure| figure.number)
+            .collect::<Vec<_>>();
+
+        let numbers = ABBREVIATIONS
+            .iter()
+            .find(|(full, abbreviations)| {
+                abbreviations
+                    .iter()
+                    .any(|abbreviated| abbreviated.iter().all(|number| written.contains(number)))
+                    && written.iter().all(|number| full.contains(number))
+            })
+            .map(|(full, _)| full.to_vec())
+            .unwrap_or(written);
+
+        let signature = key.signature();
+
+        numbers
+            .iter()
+            .map(|number| {
+                let root = bass.root.transposed_by(Steps(number - 1));
+
+                let accidental = self
+                    .figures
+                    .iter()
+                    .find(|figure| (figure.number - 1) % 7 == (number - 1) % 7)
+                    .and_then(|figure| figure.accidental)
+                    .unwrap_or_else(|| signature.accidental(root));
+
+                PitchClass { root, accidental }
+            })
+            .collect()
+    }
+}
+
+impl Display for FiguredBass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let figures = self
+            .figures
+            .iter()
+            .map(|figure| figure.to_string())
+            .collect::<Vec<_>>();
+
+        f.write_str(&figures.join("/"))
+    }
+}
+
+/// Parses figures written from top to bottom and separated by slashes, such as "6/4", "♯6" or "7/♯".
+/// An empty string stands for a root position triad.
+impl TryFrom<&str> for FiguredBass {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let figures = value
+            .split('/')
+            .filter(|figure| !figure.trim().is_empty())
+            .map(|figure| Figure::try_from(figure.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FiguredBass { figures })
+    }
+}
+
+/// The ranges of the voices in four-part writing.
+/// When realizing a figured bass, the bass line is taken as given;
+/// [check_part_writing](crate::counterpoint::check_part_writing) flags a bass outside its range.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VoiceRanges {
+    pub soprano: PitchDyad,
+    pub alto: PitchDyad,
+    pub tenor: PitchDyad,
+    pub bass: PitchDyad,
+}
+
+impl Default for VoiceRanges {
+    fn default() -> Self {
+        VoiceRanges {
+            soprano: PitchDyad::from((C.o(4), G.o(5))),
+            alto: PitchDyad::from((G.o(3), D.o(5))),
+            tenor: PitchDyad::from((C.o(3), G.o(4))),
+            bass: PitchDyad::from((E.o(2), C.o(4))),
+        }
+    }
+}
+
+/// Realizes a figured bass line in four parts, returning one pitch set per bass note,
+/// or `None` if some chord cannot be voiced within the ranges.
+///
+/// The upper voices are chosen to move as little as possible, avoiding parallel fifths and octaves,
+/// spacing wider than an octave between adjacent upper voices, and doublings of anything but the bass.
+///
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{realize_figured_bass, FiguredBass, VoiceRanges};
+/// use solfege::key::Key;
+/// use solfege::pitch::PitchRoot::{C, F, G};
+/// use solfege::pitch::PitchClassUtils;
+///
+/// let key = Key::try_from("C major").unwrap();
+///
+/// let line = [
+///     (C.o(3), FiguredBass::try_from("").unwrap()),
+///     (F.o(3), FiguredBass::try_from("").unwrap()),
+///     (G.o(3), FiguredBass::try_from("7").unwrap()),
+///     (C.o(3), FiguredBass::try_from("").unwrap()),
+/// ];
+///
+/// let chords = realize_figured_bass(&line, &key, &VoiceRanges::default()).unwrap();
+///
+/// assert_eq!(chords.len(), 4);
+/// assert!(chords.iter().all(|chord| chord.len() == 4));
+/// ```
+pub fn realize_figured_bass(
+    line: &[(Pitch, FiguredBass)],
+    key: &Key,
+    ranges: &VoiceRanges,
+) -> Option<Vec<PitchSet>> {
+    let candidates = line
+        .iter()
+        .map(|(bass, figures)| {
+            voicings(bass, &figures.pitch_classes_above(&bass.class, key), ranges)
+        })
+        .collect::<Vec<_>>();
+
+    if candidates.iter().any(|voicings| voicings.is_empty()) {
+        return None;
+    }
+
+    // For every voicing of the current chord, the lowest cost of getting there and the voicing it came from
+    let mut costs: Vec<Vec<(Scalar, usize)>> =
+        vec![candidates[0].iter().map(|(_, cost)| (*cost, 0)).collect()];
+
+    for index in 1..candidates.len() {
+        let step = candidates[index]
+            .iter()
+            .map(|(voicing, cost)| {
+                candidates[index - 1]
+                    .iter()
+                    .enumerate()
+                    .map(|(previous_index, (previous, _))| {
+                        (
+                            costs[index - 1][previous_index].0
+                                + cost
+                                + transition_cost(previous, voicing),
+                            previous_index,
+                        )
+                    })
+                    .min()
+                    .expect("Every chord has voicings")
+            })
+            .collect();
+
+        costs.push(step);
+    }
+
+    let mut index = costs
+        .last()?
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, (cost, _))| *cost)
+        .map(|(index, _)| index)?;
+
+    let mut chords = vec![];
+
+    for position in (0..candidates.len()).rev() {
+        chords.push(candidates[position][index].0.iter().copied().collect());
+        index = costs[position][index].1;
+    }
+
+    chords.reverse();
+
+    Some(chords)
+}
+
+/// Returns every voicing of the bass, tenor, alto and soprano, with a cost for its doublings.
+fn voicings(
+    bass: &Pitch,
+    above: &PitchClassSet,
+    ranges: &VoiceRanges,
+) -> Vec<([Pitch; 4], Scalar)> {
+    let mut classes = above.clone();
+    classes.insert(bass.class);
+
+    let candidates = |range: &PitchDyad| {
+        classes
+            .in_range(range)
+            .into_iter()
+            .filter(|pitch| pitch > bass)
+            .collect::<Vec<_>>()
+    };
+
+    let mut voicings = vec![];
+
+    for tenor in candidates(&ranges.tenor) {
+        for alto in candidates(&ranges.alto)
+            .into_iter()
+            .filter(|alto| *alto > tenor)
+        {
+            for soprano in candidates(&ranges.soprano)
+                .into_iter()
+                .filter(|soprano| *soprano > alto)
+            {
+                let upper = [tenor, alto, soprano];
+
+                let is_complete = above
+                    .iter()
+                    .all(|class| upper.iter().any(|pitch| pitch.class == *class));
+                let is_spaced = semitones(&soprano) - semitones(&alto) <= 12
+                    && semitones(&alto) - semitones(&tenor) <= 12;
+
+                if !is_complete || !is_spaced {
+                    continue;
+                }
+
+                let doubles_bass = upper.iter().any(|pitch| pitch.class == bass.class);
+
+                let cost = if doubles_bass || above.len() >= 3 {
+                    0
+                } else {
+                    4
+                };
+
+                voicings.push(([*bass, tenor, alto, soprano], cost));
+            }
+        }
+    }
+
+    voicings
+}
+
+fn transition_cost(from: &[Pitch; 4], to: &[Pitch; 4]) -> Scalar {
+    let motion = (1..4)
+        .map(|voice| (semitones(&to[voice]) - semitones(&from[voice])).abs())
+        .sum::<Scalar>();
+
+    let mut parallels = 0;
+
+    for lower in 0..4 {
+        for upper in lower + 1..4 {
+            let before = (semitones(&from[upper]) - semitones(&from[lower])) % 12;
+            let after = (semitones(&to[upper]) - semitones(&to[lower])) % 12;
+
+            let moves = from[lower] != to[lower] && from[upper] != to[upper];
+
+            if moves && before == after && (after == 0 || after == 7) {
+                parallels += 1;
+            }
+        }
+    }
+
+    motion + 50 * parallels
+}
+
+fn semitones(pitch: &Pitch) -> Scalar {
+    pitch.semitones_from_c0().0
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{realize_figured_bass, Figure, FiguredBass, VoiceRanges};
+    use crate::key::Key;
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassSet, PitchClassUtils, PitchRootUtils, PitchSet, SHARP};
+
+    fn key(value: &str) -> Key {
+        Key::try_from(value).unwrap()
+    }
+
+    fn figures(pitches: PitchSet, key_name: &str) -> String {
+        FiguredBass::of(&pitches, &key(key_name)).to_string()
+    }
+
+    #[test]
+    fn common_figures() {
+        assert_eq!(
+            figures(PitchSet::from([C.o(3), E.o(4), G.o(4), C.o(5)]), "C major"),
+            ""
+        );
+        assert_eq!(
+            figures(PitchSet::from([E.o(3), G.o(3), C.o(4)]), "C major"),
+            "6"
+        );
+        assert_eq!(
+            figures(PitchSet::from([G.o(2), C.o(4), E.o(4)]), "C major"),
+            "6/4"
+        );
+        assert_eq!(
+            figures(PitchSet::from([G.o(2), B.o(3), D.o(4), F.o(4)]), "C major"),
+            "7"
+        );
+        assert_eq!(
+            figures(PitchSet::from([B.o(2), D.o(3), F.o(3), G.o(3)]), "C major"),
+            "6/5"
+        );
+        assert_eq!(
+            figures(PitchSet::from([D.o(3), F.o(3), G.o(3), B.o(3)]), "C major"),
+            "4/3"
+        );
+    }
+
+    #[test]
+    fn accidental_figures() {
+        // V in A minor has a raised third
+        assert_eq!(
+            figures(PitchSet::from([E.o(3), G.sharp().o(3), B.o(3)]), "A minor"),
+            "♯"
+        );
+
+        // V⁷ in A minor
+        assert_eq!(
+            figures(
+                PitchSet::from([E.o(3), G.sharp().o(3), B.o(3), D.o(4)]),
+                "A minor"
+            ),
+            "7/♯"
+        );
+
+        // The bass itself carries its accidental, so V⁶ in A minor needs none
+        assert_eq!(
+            figures(PitchSet::from([G.sharp().o(2), B.o(3), E.o(4)]), "A minor"),
+            "6"
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(
+            FiguredBass::try_from("6/♯4").unwrap().figures,
+            vec![
+                Figure {
+                    number: 6,
+                    accidental: None
+                },
+                Figure {
+                    number: 4,
+                    accidental: Some(SHARP)
+                },
+            ],
+        );
+        assert!(FiguredBass::try_from("x?").is_err());
+    }
+
+    #[test]
+    fn abbreviations_are_filled_in() {
+        let c_major = key("C major");
+
+        assert_eq!(
+            FiguredBass::try_from("")
+                .unwrap()
+                .pitch_classes_above(&C.natural(), &c_major),
+            PitchClassSet::from([E.natural(), G.natural()]),
+        );
+        assert_eq!(
+            FiguredBass::try_from("6")
+                .unwrap()
+                .pitch_classes_above(&E.natural(), &c_major),
+            PitchClassSet::from([G.natural(), C.natural()]),
+        );
+        assert_eq!(
+            FiguredBass::try_from("4/2")
+                .unwrap()
+                .pitch_classes_above(&F.natural(), &c_major),
+            PitchClassSet::from([G.natural(), B.natural(), D.natural()]),
+        );
+        assert_eq!(
+            FiguredBass::try_from("2")
+                .unwrap()
+                .pitch_classes_above(&F.natural(), &c_major),
+            PitchClassSet::from([G.natural(), B.natural(), D.natural()]),
+        );
+        assert_eq!(
+            FiguredBass::try_from("7/♯")
+                .unwrap()
+                .pitch_classes_above(&E.natural(), &key("A minor")),
+            PitchClassSet::from([G.sharp(), B.natural(), D.natural()]),
+        );
+    }
+
+    #[test]
+    fn realization() {
+        let a_minor = key("A minor");
+
+        let line = [
+            (A.o(2), FiguredBass::try_from("").unwrap()),
+            (D.o(3), FiguredBass::try_from("").unwrap()),
+            (E.o(3), FiguredBass::try_from("♯").unwrap()),
+            (A.o(2), FiguredBass::try_from("").unwrap()),
+        ];
+
+        let ranges = VoiceRanges::default();
+
+        let chords = realize_figured_bass(&line, &a_minor, &ranges).unwrap();
+
+        assert_eq!(chords.len(), 4);
+
+        for (chord, (bass, _)) in chords.iter().zip(line.iter()) {
+            assert_eq!(chord.len(), 4);
+            assert_eq!(chord.iter().next(), Some(bass));
+        }
+
+        assert!(chords[2].iter().any(|pitch| pitch.class == G.sharp()));
+        assert!(chords[1..]
+            .iter()
+            .all(|chord| chord.iter().all(|pitch| pitch.class != G.natural())));
+    }
+
+    #[test]
+    fn impossible_ranges() {
+        let ranges = VoiceRanges {
+            soprano: (C.o(4), D.o(4)).into(),
+            alto: (C.o(4), D.o(4)).into(),
+            tenor: (C.o(4), D.o(4)).into(),
+            ..VoiceRanges::default()
+        };
+
+        let line = [(C.o(3), FiguredBass::try_from("").unwrap())];
+
+        assert_eq!(realize_figured_bass(&line, &key("C major"), &ranges), None);
+    }
+}
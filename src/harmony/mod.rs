@@ -1,5 +1,28 @@
+mod chord;
+mod chord_identification;
+mod chord_quality;
+mod chord_symbol;
 mod dyad;
 mod dyad_set;
+mod figured_bass;
+mod neo_riemannian;
+mod parser;
+mod progression;
+mod roman_numeral;
+mod sonority;
+mod voice_leading;
+mod voicing;
 
+pub use chord::*;
+pub use chord_identification::*;
+pub use chord_quality::*;
+pub use chord_symbol::*;
 pub use dyad::*;
 pub use dyad_set::*;
+pub use figured_bass::*;
+pub use neo_riemannian::*;
+pub use progression::*;
+pub use roman_numeral::*;
+pub use sonority::*;
+pub use voice_leading::*;
+pub use voicing::*;
@@ -0,0 +1,305 @@
+use crate::harmony::{Chord, ChordQuality};
+use crate::interval::IntervalRoot::{Sixth, Third};
+use crate::interval::{major, minor};
+use crate::vertical::TransposedBy;
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::{Display, Formatter};
+
+/// A [neo-Riemannian](https://en.wikipedia.org/wiki/Neo-Riemannian_theory) transformation between major and minor triads.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Transformation {
+    /// Parallel: C major and C minor.
+    P,
+    /// Leading-tone exchange: C major and E minor.
+    L,
+    /// Relative: C major and A minor.
+    R,
+    /// Nebenverwandt, R then L then P: C major and F minor.
+    N,
+    /// Slide, L then P then R: C major and C♯ minor.
+    S,
+    /// Hexatonic pole, L then P then L: C major and G♯ minor.
+    H,
+}
+
+pub const TRANSFORMATIONS: [Transformation; 6] = [
+    Transformation::P,
+    Transformation::L,
+    Transformation::R,
+    Transformation::N,
+    Transformation::S,
+    Transformation::H,
+];
+
+impl Transformation {
+    /// Applies the transformation to a major or minor triad, returning it in root position,
+    /// or `None` for any other chord.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::harmony::{Chord, ChordQuality, Transformation};
+    /// use solfege::pitch::PitchRoot::{B, E};
+    /// use solfege::pitch::PitchRootUtils;
+    ///
+    /// let e_minor = Chord::new(E.natural(), ChordQuality::Minor);
+    ///
+    /// assert_eq!(Transformation::P.apply(&e_minor), Some(Chord::new(E.natural(), ChordQuality::Major)));
+    /// assert_eq!(Transformation::S.apply(&e_minor), Some(Chord::new(E.flat(), ChordQuality::Major)));
+    /// assert_eq!(Transformation::N.apply(&e_minor), Some(Chord::new(B.natural(), ChordQuality::Major)));
+    /// assert_eq!(Transformation::P.apply(&Chord::new(E.natural(), ChordQuality::Diminished)), None);
+    /// ```
+    pub fn apply(&self, chord: &Chord) -> Option<Chord> {
+        let is_major = match chord.quality {
+            ChordQuality::Major => true,
+            ChordQuality::Minor => false,
+            _ => return None,
+        };
+
+        let (root, quality) = match (*self, is_major) {
+            (Self::P, true) => (chord.root, ChordQuality::Minor),
+            (Self::P, false) => (chord.root, ChordQuality::Major),
+            (Self::L, true) => (chord.root.transposed_by(&major(Third)), ChordQuality::Minor),
+            (Self::L, false) => (chord.root.transposed_by(&minor(Sixth)), ChordQuality::Major),
+            (Self::R, true) => (chord.root.transposed_by(&major(Sixth)), ChordQuality::Minor),
+            (Self::R, false) => (chord.root.transposed_by(&minor(Third)), ChordQuality::Major),
+            (Self::N, _) => return apply_transformations(chord, &[Self::R, Self::L, Self::P]),
+            (Self::S, _) => return apply_transformations(chord, &[Self::L, Self::P, Self::R]),
+            (Self::H, _) => return apply_transformations(chord, &[Self::L, Self::P, Self::L]),
+        };
+
+        Some(Chord::new(root, quality))
+    }
+}
+
+impl Display for Transformation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::P => "P",
+            Self::L => "L",
+            Self::R => "R",
+            Self::N => "N",
+            Self::S => "S",
+            Self::H => "H",
+        })
+    }
+}
+
+impl TryFrom<&str> for Transformation {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        TRANSFORMATIONS
+            .into_iter()
+            .find(|transformation| transformation.to_string() == value)
+            .ok_or("Unknown transformation")
+    }
+}
+
+/// Applies transformations one after the other, or returns `None` if the chord is not a major or minor triad.
+pub fn apply_transformations(chord: &Chord, transformations: &[Transformation]) -> Option<Chord> {
+    transformations.iter().try_fold(
+        Chord::new(chord.root, chord.quality),
+        |chord, transformation| transformation.apply(&chord),
+    )
+}
+
+/// Returns one of the shortest sequences of P, L and R moves on the [Tonnetz](https://en.wikipedia.org/wiki/Tonnetz)
+/// between two major or minor triads, comparing them enharmonically,
+/// or `None` if either chord is not a major or minor triad.
+///
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{tonnetz_path, Chord, ChordQuality, Transformation};
+/// use solfege::pitch::PitchRoot::{A, C};
+/// use solfege::pitch::PitchRootUtils;
+///
+/// let c_major = Chord::new(C.natural(), ChordQuality::Major);
+/// let a_flat_minor = Chord::new(A.flat(), ChordQuality::Minor);
+///
+/// assert_eq!(
+///     tonnetz_path(&c_major, &a_flat_minor),
+///     Some(vec![Transformation::P, Transformation::L, Transformation::P]),
+/// );
+/// ```
+pub fn tonnetz_path(from: &Chord, to: &Chord) -> Option<Vec<Transformation>> {
+    shortest_path(from, to, &TRANSFORMATIONS[..3])
+}
+
+/// Returns the transformations between consecutive triads of a progression,
+/// preferring single compound transformations over chains of P, L and R,
+/// or `None` if some chord is not a major or minor triad.
+///
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{transformation_chains, Chord, ChordQuality, Transformation};
+/// use solfege::pitch::PitchRoot::{A, C, E, F};
+/// use solfege::pitch::PitchRootUtils;
+///
+/// let progression = [
+///     Chord::new(C.natural(), ChordQuality::Major),
+///     Chord::new(A.natural(), ChordQuality::Minor),
+///     Chord::new(F.natural(), ChordQuality::Minor),
+///     Chord::new(E.natural(), ChordQuality::Major),
+/// ];
+///
+/// assert_eq!(
+///     transformation_chains(&progression),
+///     Some(vec![
+///         vec![Transformation::R],
+///         vec![Transformation::L, Transformation::P],
+///         vec![Transformation::S],
+///     ]),
+/// );
+/// ```
+pub fn transformation_chains(chords: &[Chord]) -> Option<Vec<Vec<Transformation>>> {
+    chords
+        .windows(2)
+        .map(|pair| {
+            let path = shortest_path(&pair[0], &pair[1], &TRANSFORMATIONS)?;
+
+            match path.len() {
+                0 | 1 => Some(path),
+                _ => tonnetz_path(&pair[0], &pair[1]),
+            }
+        })
+        .collect()
+}
+
+/// Breadth-first search over triads, identifying them by the integer notation of their root and their quality.
+fn shortest_path(
+    from: &Chord,
+    to: &Chord,
+    moves: &[Transformation],
+) -> Option<Vec<Transformation>> {
+    let is_triad =
+        |chord: &Chord| matches!(chord.quality, ChordQuality::Major | ChordQuality::Minor);
+
+    if !is_triad(from) || !is_triad(to) {
+        return None;
+    }
+
+    let key = |chord: &Chord| (chord.root.integer_notation(), chord.quality);
+
+    let target = key(to);
+
+    let mut visited = BTreeSet::from([key(from)]);
+    let mut queue = VecDeque::from([(Chord::new(from.root, from.quality), vec![])]);
+
+    while let Some((chord, path)) = queue.pop_front() {
+        if key(&chord) == target {
+            return Some(path);
+        }
+
+        for transformation in moves {
+            let next = transformation.apply(&chord)?;
+
+            if visited.insert(key(&next)) {
+                let mut next_path = path.clone();
+                next_path.push(*transformation);
+
+                queue.push_back((next, next_path));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{
+        apply_transformations, tonnetz_path, transformation_chains, Chord, ChordQuality,
+        Transformation,
+    };
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::PitchRootUtils;
+
+    #[test]
+    fn simple_transformations() {
+        let c_major = Chord::new(C.natural(), ChordQuality::Major);
+
+        assert_eq!(
+            Transformation::P.apply(&c_major),
+            Some(Chord::new(C.natural(), ChordQuality::Minor))
+        );
+        assert_eq!(
+            Transformation::L.apply(&c_major),
+            Some(Chord::new(E.natural(), ChordQuality::Minor))
+        );
+        assert_eq!(
+            Transformation::R.apply(&c_major),
+            Some(Chord::new(A.natural(), ChordQuality::Minor))
+        );
+        assert_eq!(
+            Transformation::N.apply(&c_major),
+            Some(Chord::new(F.natural(), ChordQuality::Minor))
+        );
+        assert_eq!(
+            Transformation::S.apply(&c_major),
+            Some(Chord::new(C.sharp(), ChordQuality::Minor))
+        );
+        assert_eq!(
+            Transformation::H.apply(&c_major),
+            Some(Chord::new(G.sharp(), ChordQuality::Minor))
+        );
+    }
+
+    #[test]
+    fn transformations_are_involutions() {
+        let chords = [
+            Chord::new(E.flat(), ChordQuality::Major),
+            Chord::new(F.sharp(), ChordQuality::Minor),
+        ];
+
+        for chord in chords {
+            for transformation in crate::harmony::TRANSFORMATIONS {
+                assert_eq!(
+                    apply_transformations(&chord, &[transformation, transformation]),
+                    Some(chord)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn paths() {
+        let c_major = Chord::new(C.natural(), ChordQuality::Major);
+
+        assert_eq!(tonnetz_path(&c_major, &c_major), Some(vec![]));
+        assert_eq!(
+            tonnetz_path(&c_major, &Chord::new(G.natural(), ChordQuality::Major))
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            tonnetz_path(&c_major, &Chord::new(F.sharp(), ChordQuality::Major))
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Transformation::try_from("H"), Ok(Transformation::H));
+        assert!(Transformation::try_from("X").is_err());
+    }
+
+    #[test]
+    fn only_triads() {
+        let c_major = Chord::new(C.natural(), ChordQuality::Major);
+        let diminished = Chord::new(B.natural(), ChordQuality::Diminished);
+        let seventh = Chord::new(G.natural(), ChordQuality::DominantSeventh);
+
+        assert_eq!(Transformation::P.apply(&diminished), None);
+        assert_eq!(apply_transformations(&seventh, &[Transformation::R]), None);
+        assert_eq!(tonnetz_path(&c_major, &diminished), None);
+        assert_eq!(tonnetz_path(&seventh, &c_major), None);
+        assert_eq!(transformation_chains(&[c_major, seventh, c_major]), None);
+        assert_eq!(transformation_chains(&[c_major]), Some(vec![]));
+    }
+}
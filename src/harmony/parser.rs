@@ -0,0 +1,49 @@
+use crate::pitch::Accidental;
+use crate::vertical::Semitones;
+
+/// A cursor over a string, shared by the chord symbol and Roman numeral parsers.
+pub(super) struct Parser<'a> {
+    pub(super) rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn new(value: &'a str) -> Self {
+        Parser { rest: value.trim() }
+    }
+
+    /// Consumes the prefix if the rest of the string starts with it.
+    pub(super) fn eat(&mut self, prefix: &str) -> bool {
+        match self.rest.strip_prefix(prefix) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(super) fn eat_any(&mut self, prefixes: &[&str]) -> bool {
+        prefixes.iter().any(|prefix| self.eat(prefix))
+    }
+
+    /// Consumes any number of ASCII or Unicode accidentals, which is natural if there are none.
+    pub(super) fn accidental(&mut self) -> Accidental {
+        let mut offset = 0;
+
+        loop {
+            if self.eat_any(&["♭", "b"]) {
+                offset -= 1;
+            } else if self.eat_any(&["♯", "#"]) {
+                offset += 1;
+            } else if self.eat("𝄫") {
+                offset -= 2;
+            } else if self.eat("𝄪") {
+                offset += 2;
+            } else if !self.eat("♮") {
+                return Accidental {
+                    offset: Semitones(offset),
+                };
+            }
+        }
+    }
+}
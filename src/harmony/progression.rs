@@ -0,0 +1,482 @@
+use crate::harmony::{Chord, RomanNumeral};
+use crate::key::{Key, Mode};
+use crate::pitch::PitchSet;
+use std::fmt::{Display, Formatter};
+
+/// The [function](https://en.wikipedia.org/wiki/Function_(music)) of a chord within a phrase.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum HarmonicFunction {
+    Tonic,
+    Predominant,
+    Dominant,
+}
+
+impl HarmonicFunction {
+    /// Returns the functions that may follow this one, with their weights.
+    fn successors(&self) -> &'static [(HarmonicFunction, u64)] {
+        match *self {
+            Self::Tonic => &[
+                (Self::Tonic, 1),
+                (Self::Predominant, 3),
+                (Self::Dominant, 2),
+            ],
+            Self::Predominant => &[(Self::Predominant, 1), (Self::Dominant, 3)],
+            Self::Dominant => &[(Self::Tonic, 3), (Self::Dominant, 1)],
+        }
+    }
+
+    /// Returns whether a function can be reached in exactly a number of moves.
+    fn reaches(&self, target: HarmonicFunction, moves: usize) -> bool {
+        match moves {
+            0 => *self == target,
+            _ => self
+                .successors()
+                .iter()
+                .any(|(next, _)| next.reaches(target, moves - 1)),
+        }
+    }
+
+    /// Returns the Roman numerals that can fulfil the function in a mode.
+    fn numerals(&self, mode: Mode) -> &'static [&'static str] {
+        match (*self, mode) {
+            (Self::Tonic, Mode::Major) => &["I", "vi", "I6"],
+            (Self::Tonic, Mode::Minor) => &["i", "VI", "i6"],
+            (Self::Predominant, Mode::Major) => &["IV", "ii", "ii6", "ii65"],
+            (Self::Predominant, Mode::Minor) => &["iv", "iio6", "iv6"],
+            (Self::Dominant, Mode::Major) => &["V", "V7", "viio6", "V6"],
+            (Self::Dominant, Mode::Minor) => &["V", "V7", "viio7", "V6"],
+        }
+    }
+}
+
+impl Display for HarmonicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Tonic => "tonic",
+            Self::Predominant => "predominant",
+            Self::Dominant => "dominant",
+        })
+    }
+}
+
+/// The [cadence](https://en.wikipedia.org/wiki/Cadence) that closes a phrase.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Cadence {
+    /// V to I.
+    Authentic,
+    /// Ending on V.
+    Half,
+    /// IV to I.
+    Plagal,
+    /// V to vi.
+    Deceptive,
+}
+
+impl Cadence {
+    /// Returns the candidates for the last two chords.
+    fn numerals(&self, mode: Mode) -> [&'static [&'static str]; 2] {
+        let major = mode == Mode::Major;
+
+        match *self {
+            Self::Authentic => [&["V", "V7"], if major { &["I"] } else { &["i"] }],
+            Self::Half => [HarmonicFunction::Predominant.numerals(mode), &["V"]],
+            Self::Plagal => [
+                if major { &["IV"] } else { &["iv"] },
+                if major { &["I"] } else { &["i"] },
+            ],
+            Self::Deceptive => [&["V", "V7"], if major { &["vi"] } else { &["VI"] }],
+        }
+    }
+
+    /// Returns the function of the chord just before the cadence.
+    fn approach(&self) -> HarmonicFunction {
+        match *self {
+            Self::Authentic | Self::Deceptive => HarmonicFunction::Predominant,
+            Self::Half | Self::Plagal => HarmonicFunction::Tonic,
+        }
+    }
+}
+
+impl Display for Cadence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Authentic => "authentic cadence",
+            Self::Half => "half cadence",
+            Self::Plagal => "plagal cadence",
+            Self::Deceptive => "deceptive cadence",
+        })
+    }
+}
+
+/// Settings for generating phrases.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProgressionOptions {
+    /// The number of chords in a phrase, including the cadence.
+    pub phrase_length: usize,
+    /// The cadences to choose from at the end of each phrase.
+    pub cadences: Vec<Cadence>,
+    /// The chance, from 0 to 1, of preceding a chord by its secondary dominant.
+    pub secondary_dominants: f64,
+}
+
+impl Default for ProgressionOptions {
+    fn default() -> Self {
+        ProgressionOptions {
+            phrase_length: 8,
+            cadences: vec![
+                Cadence::Authentic,
+                Cadence::Half,
+                Cadence::Deceptive,
+                Cadence::Plagal,
+            ],
+            secondary_dominants: 0.2,
+        }
+    }
+}
+
+/// Generates endless phrases of functional harmony in a key.
+/// Phrases start on the tonic, move from tonic to predominant to dominant regions, and end with a cadence.
+/// The same seed always gives the same phrases.
+///
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{Cadence, ProgressionGenerator, ProgressionOptions};
+/// use solfege::key::Key;
+///
+/// let options = ProgressionOptions {
+///     phrase_length: 6,
+///     cadences: vec![Cadence::Authentic],
+///     ..ProgressionOptions::default()
+/// };
+///
+/// let mut generator = ProgressionGenerator::new(Key::try_from("G major").unwrap(), options.clone(), 42);
+///
+/// let phrase = generator.phrase();
+///
+/// assert_eq!(phrase.len(), 6);
+/// assert_eq!(phrase[0].to_string(), "I");
+/// assert_eq!(phrase[5].to_string(), "I");
+///
+/// // The same seed gives the same phrase
+/// let mut again = ProgressionGenerator::new(Key::try_from("G major").unwrap(), options, 42);
+///
+/// assert_eq!(again.phrase(), phrase);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProgressionGenerator {
+    pub key: Key,
+    pub options: ProgressionOptions,
+    random: Random,
+}
+
+impl ProgressionGenerator {
+    pub fn new(key: Key, options: ProgressionOptions, seed: u64) -> Self {
+        assert!(
+            options.phrase_length >= 2,
+            "A phrase needs at least two chords for its cadence"
+        );
+        assert!(
+            !options.cadences.is_empty(),
+            "At least one cadence is needed"
+        );
+
+        ProgressionGenerator {
+            key,
+            options,
+            random: Random { state: seed },
+        }
+    }
+
+    /// Generates the Roman numerals of the next phrase.
+    pub fn phrase(&mut self) -> Vec<RomanNumeral> {
+        let mode = self.key.mode;
+        let cadence = self.random.choose(&self.options.cadences);
+
+        let body_length = self.options.phrase_length - 2;
+
+        let mut functions = vec![];
+
+        for index in 0..body_length {
+            let function = match functions.last() {
+                None => HarmonicFunction::Tonic,
+                Some(previous) => {
+                    let moves_left = body_length - 1 - index;
+
+                    let successors = HarmonicFunction::successors(previous)
+                        .iter()
+                        .filter(|(next, _)| next.reaches(cadence.approach(), moves_left))
+                        .copied()
+                        .collect::<Vec<_>>();
+
+                    self.random.choose_weighted(&successors)
+                }
+            };
+
+            functions.push(function);
+        }
+
+        let mut labels: Vec<String> = vec![];
+
+        for (index, function) in functions.iter().enumerate() {
+            let label = match index {
+                0 => function.numerals(mode)[0],
+                _ => self.choose_label(function.numerals(mode), labels.last()),
+            };
+
+            labels.push(label.to_string());
+        }
+
+        for candidates in cadence.numerals(mode) {
+            let label = self.choose_label(candidates, labels.last());
+
+            labels.push(label.to_string());
+        }
+
+        // Precede chords by their secondary dominants, leaving the opening tonic and the cadence alone
+        let cadence_start = labels.len().saturating_sub(cadence.numerals(mode).len());
+
+        for index in (1..cadence_start).rev() {
+            let target = degree(&labels[index + 1]);
+
+            let can_be_tonicized =
+                !["I", "i"].contains(&target) && !labels[index + 1].contains(['o', '/']);
+
+            if can_be_tonicized && self.random.chance(self.options.secondary_dominants) {
+                let dominant = self.random.choose(&["V", "V7"]);
+
+                labels[index] = format!("{}/{}", dominant, target);
+            }
+        }
+
+        labels
+            .iter()
+            .map(|label| RomanNumeral::try_from(label.as_str()).expect("The numerals are valid"))
+            .collect()
+    }
+
+    /// Generates the spelled chords of the next phrase.
+    pub fn chords(&mut self) -> Vec<Chord> {
+        let key = self.key;
+
+        self.phrase()
+            .iter()
+            .map(|numeral| numeral.chord(&key).expect("Generated numerals are chords"))
+            .collect()
+    }
+
+    /// Generates the next phrase as chords in close position, with the bass in the given octave.
+    pub fn pitch_sets(&mut self, bass_octave: i32) -> Vec<PitchSet> {
+        self.chords()
+            .iter()
+            .map(|chord| chord.pitches(bass_octave).into_iter().collect())
+            .collect()
+    }
+
+    /// Picks a label, avoiding a repetition of the previous one when possible.
+    fn choose_label(
+        &mut self,
+        candidates: &[&'static str],
+        previous: Option<&String>,
+    ) -> &'static str {
+        let fresh = candidates
+            .iter()
+            .copied()
+            .filter(|candidate| previous.is_none_or(|previous| previous != candidate))
+            .collect::<Vec<_>>();
+
+        match fresh.is_empty() {
+            true => self.random.choose(candidates),
+            false => self.random.choose(&fresh),
+        }
+    }
+}
+
+impl Iterator for ProgressionGenerator {
+    type Item = Vec<RomanNumeral>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.phrase())
+    }
+}
+
+/// Returns the numeral of a label without its figures, as in "ii" for "ii65".
+fn degree(label: &str) -> &str {
+    label.trim_end_matches(|character: char| character.is_ascii_digit())
+}
+
+/// A [SplitMix64](https://prng.di.unimi.it/splitmix64.c) pseudo-random number generator.
+#[derive(Clone, Debug)]
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        ((self.next() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    fn choose<T: Copy>(&mut self, values: &[T]) -> T {
+        values[(self.next() % values.len() as u64) as usize]
+    }
+
+    fn choose_weighted<T: Copy>(&mut self, values: &[(T, u64)]) -> T {
+        let total = values.iter().map(|(_, weight)| weight).sum::<u64>();
+
+        let mut pick = self.next() % total;
+
+        for (value, weight) in values {
+            if pick < *weight {
+                return *value;
+            }
+
+            pick -= weight;
+        }
+
+        unreachable!("The pick is below the total weight")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{Cadence, ProgressionGenerator, ProgressionOptions, RomanNumeral};
+    use crate::key::{Key, Mode};
+
+    fn generator(
+        key: &str,
+        cadence: Cadence,
+        secondary_dominants: f64,
+        seed: u64,
+    ) -> ProgressionGenerator {
+        let options = ProgressionOptions {
+            phrase_length: 8,
+            cadences: vec![cadence],
+            secondary_dominants,
+        };
+
+        ProgressionGenerator::new(Key::try_from(key).unwrap(), options, seed)
+    }
+
+    fn labels(generator: &mut ProgressionGenerator) -> Vec<String> {
+        generator
+            .phrase()
+            .iter()
+            .map(|numeral| numeral.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn cadences() {
+        for seed in 0..20 {
+            let authentic = labels(&mut generator("C major", Cadence::Authentic, 0.0, seed));
+            assert!(authentic[6].starts_with('V'));
+            assert_eq!(authentic[7], "I");
+
+            let half = labels(&mut generator("C major", Cadence::Half, 0.0, seed));
+            assert_eq!(half[7], "V");
+
+            let plagal = labels(&mut generator("A minor", Cadence::Plagal, 0.0, seed));
+            assert_eq!(plagal[6..], ["iv", "i"]);
+
+            let deceptive = labels(&mut generator("A minor", Cadence::Deceptive, 0.0, seed));
+            assert_eq!(deceptive[7], "VI");
+        }
+    }
+
+    #[test]
+    fn reproducible() {
+        let first = generator("E♭ major", Cadence::Authentic, 0.3, 7)
+            .take(4)
+            .collect::<Vec<_>>();
+        let second = generator("E♭ major", Cadence::Authentic, 0.3, 7)
+            .take(4)
+            .collect::<Vec<_>>();
+        let other = generator("E♭ major", Cadence::Authentic, 0.3, 8)
+            .take(4)
+            .collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn secondary_dominants() {
+        for seed in 0..20 {
+            let phrase = labels(&mut generator("C major", Cadence::Authentic, 1.0, seed));
+
+            assert!(phrase.iter().any(|label| label.contains('/')));
+
+            // Secondary dominants resolve to the chord they tonicize
+            for pair in phrase.windows(2) {
+                if let Some((_, target)) = pair[0].split_once('/') {
+                    assert!(pair[1].starts_with(target), "{:?}", phrase);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn secondary_dominants_keep_the_cadence() {
+        let cadences = [
+            Cadence::Authentic,
+            Cadence::Half,
+            Cadence::Plagal,
+            Cadence::Deceptive,
+        ];
+
+        for (key, mode) in [("C major", Mode::Major), ("A minor", Mode::Minor)] {
+            for cadence in cadences {
+                for seed in 0..20 {
+                    let phrase = labels(&mut generator(key, cadence, 1.0, seed));
+
+                    for (label, candidates) in phrase[6..].iter().zip(cadence.numerals(mode)) {
+                        let candidates = candidates
+                            .iter()
+                            .map(|candidate| {
+                                RomanNumeral::try_from(*candidate).unwrap().to_string()
+                            })
+                            .collect::<Vec<_>>();
+
+                        assert!(candidates.contains(label), "{} {:?}", cadence, phrase);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn functional_order() {
+        // Without secondary dominants, a dominant is never directly followed by a predominant
+        for seed in 0..50 {
+            let phrase = labels(&mut generator("C major", Cadence::Authentic, 0.0, seed));
+
+            for pair in phrase.windows(2) {
+                let is_dominant = pair[0].starts_with('V') || pair[0].starts_with("vii");
+                let is_predominant = pair[1].starts_with("IV") || pair[1].starts_with("ii");
+
+                assert!(!(is_dominant && is_predominant), "{:?}", phrase);
+            }
+        }
+    }
+
+    #[test]
+    fn spelled_chords() {
+        let mut generator = generator("F♯ minor", Cadence::Authentic, 0.5, 11);
+
+        assert_eq!(generator.chords().len(), 8);
+        assert!(generator
+            .pitch_sets(3)
+            .iter()
+            .all(|pitches| pitches.len() >= 3));
+    }
+}
@@ -0,0 +1,819 @@
+use crate::common::Scalar;
+use crate::harmony::parser::Parser;
+use crate::harmony::{Chord, ChordQuality, IdentifyChords, Inversion};
+use crate::interval::IntervalRoot::{Fifth, Fourth, Second, Seventh, Sixth, Third, Unison};
+use crate::interval::{augmented, major, minor, perfect, IntervalClass, IntervalRoot};
+use crate::key::{Key, Mode};
+use crate::pitch::{Accidental, PitchClass, PitchClassSet, PitchSet, NATURAL};
+use crate::scale::{Scale, ScaleKind};
+use crate::vertical::{InSteps, Semitones, Steps, TransposedBy};
+use std::fmt::{Display, Formatter};
+
+/// One of the three [augmented sixth chords](https://en.wikipedia.org/wiki/Augmented_sixth_chord).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum AugmentedSixth {
+    Italian,
+    French,
+    German,
+}
+
+/// The chord that a Roman numeral stands for, relative to its key.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Numeral {
+    /// A chord built on a scale degree, which may be chromatically altered as in ♭VI.
+    Degree {
+        degree: IntervalRoot,
+        alteration: Accidental,
+        quality: ChordQuality,
+    },
+    /// The major chord on the lowered second degree.
+    Neapolitan,
+    AugmentedSixth(AugmentedSixth),
+}
+
+/// The degree that a secondary chord is applied to, as in V⁷/V.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Tonicization {
+    pub degree: IntervalRoot,
+    pub alteration: Accidental,
+    pub mode: Mode,
+}
+
+/// A [Roman numeral](https://en.wikipedia.org/wiki/Roman_numeral_analysis) label of a chord in a key,
+/// such as I, ii⁶, V⁴₃, vii°⁷, ♭VI, N⁶, Ger⁺⁶ or V⁷/V.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::harmony::{Chord, ChordQuality, Inversion, RomanNumeral};
+/// use solfege::key::Key;
+/// use solfege::pitch::PitchRoot::{A, B, C, D, F};
+/// use solfege::pitch::{PitchClassSet, PitchRootUtils};
+///
+/// let key = Key::try_from("E♭ major").unwrap();
+///
+/// let chord = Chord { root: B.flat(), quality: ChordQuality::DominantSeventh, inversion: Inversion::Second };
+///
+/// assert_eq!(RomanNumeral::analyze(&chord, &key).unwrap().to_string(), "V⁴₃");
+///
+/// let (numeral, key) = RomanNumeral::parse_in_key("V6/5 of iii in E♭").unwrap();
+///
+/// assert_eq!(numeral.to_string(), "V⁶₅/iii");
+/// assert_eq!(numeral.bass(&key), F.sharp());
+/// assert_eq!(
+///     numeral.pitch_classes(&key),
+///     PitchClassSet::from([D.natural(), F.sharp(), A.natural(), C.natural()]),
+/// );
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct RomanNumeral {
+    pub numeral: Numeral,
+    pub inversion: Inversion,
+    pub of: Option<Tonicization>,
+}
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+impl RomanNumeral {
+    /// Returns the Roman numeral of a chord in a key, if it has one.
+    ///
+    /// Chromatic chords are read as secondary dominants or leading-tone chords when possible,
+    /// and as altered scale degrees otherwise.
+    pub fn analyze(chord: &Chord, key: &Key) -> Option<Self> {
+        if matches!(
+            chord.quality,
+            ChordQuality::Sus2
+                | ChordQuality::Sus4
+                | ChordQuality::MajorSixth
+                | ChordQuality::MinorSixth
+        ) {
+            return None;
+        }
+
+        if chord.quality == ChordQuality::Major
+            && chord.root == key.tonic.transposed_by(&minor(Second))
+        {
+            return Some(RomanNumeral {
+                numeral: Numeral::Neapolitan,
+                inversion: chord.inversion,
+                of: None,
+            });
+        }
+
+        let numeral = degree_numeral(chord, key);
+
+        let is_diatonic = chord
+            .pitch_classes()
+            .is_subset(&diatonic_pitch_classes(key));
+
+        if !is_diatonic {
+            if let Some(secondary) = secondary(chord, key) {
+                return Some(secondary);
+            }
+        }
+
+        Some(RomanNumeral {
+            numeral,
+            inversion: chord.inversion,
+            of: None,
+        })
+    }
+
+    /// Returns the Roman numeral of a set of pitches in a key, recognising augmented sixth chords.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::harmony::RomanNumeral;
+    /// use solfege::key::Key;
+    /// use solfege::pitch::PitchRoot::{A, C, E, F};
+    /// use solfege::pitch::{PitchClassUtils, PitchRootUtils, PitchSet};
+    ///
+    /// let key = Key::try_from("C minor").unwrap();
+    /// let pitches = PitchSet::from([A.flat().o(3), C.o(4), E.flat().o(4), F.sharp().o(4)]);
+    ///
+    /// assert_eq!(RomanNumeral::analyze_pitches(&pitches, &key).unwrap().to_string(), "Ger⁺⁶");
+    /// ```
+    pub fn analyze_pitches(pitches: &PitchSet, key: &Key) -> Option<Self> {
+        let classes = pitches
+            .iter()
+            .map(|pitch| pitch.class)
+            .collect::<PitchClassSet>();
+
+        for kind in [
+            AugmentedSixth::Italian,
+            AugmentedSixth::French,
+            AugmentedSixth::German,
+        ] {
+            let numeral = RomanNumeral {
+                numeral: Numeral::AugmentedSixth(kind),
+                inversion: Inversion::Root,
+                of: None,
+            };
+
+            if numeral.pitch_classes(key) == classes {
+                return Some(numeral);
+            }
+        }
+
+        let candidate = pitches
+            .identify_chords()
+            .into_iter()
+            .find(|candidate| candidate.symbol.added.is_empty() && candidate.inversion.is_some())?;
+
+        let chord = Chord {
+            root: candidate.symbol.root,
+            quality: candidate.symbol.quality,
+            inversion: candidate.inversion.unwrap_or(Inversion::Root),
+        };
+
+        Self::analyze(&chord, key)
+    }
+
+    /// Parses a Roman numeral followed by the key it is in, such as "V6/5 of iii in E♭".
+    ///
+    /// Keys without a mode are major when the tonic is written in upper case, and minor otherwise.
+    pub fn parse_in_key(value: &str) -> Result<(Self, Key), &'static str> {
+        let (numeral, key) = value.rsplit_once(" in ").ok_or("String has no key")?;
+
+        let key = key.trim();
+
+        let key = Key::try_from(key).or_else(|_| {
+            let mode = if key.starts_with(char::is_lowercase) {
+                Mode::Minor
+            } else {
+                Mode::Major
+            };
+
+            let mut chars = key.chars();
+            let root = chars.next().ok_or("Missing key")?.to_ascii_uppercase();
+
+            let tonic = PitchClass::try_from(format!("{}{}", root, chars.as_str()).as_str())?;
+
+            Ok::<Key, &'static str>(Key { tonic, mode })
+        })?;
+
+        Ok((RomanNumeral::try_from(numeral.trim())?, key))
+    }
+
+    /// Returns the key that the chord belongs to, which differs from the given key for secondary chords.
+    pub fn local_key(&self, key: &Key) -> Key {
+        match self.of {
+            None => *key,
+            Some(of) => Key {
+                tonic: scale_degree(key, of.degree, of.alteration),
+                mode: of.mode,
+            },
+        }
+    }
+
+    /// Returns the chord, unless it is an augmented sixth chord.
+    pub fn chord(&self, key: &Key) -> Option<Chord> {
+        let key = self.local_key(key);
+
+        match self.numeral {
+            Numeral::Degree {
+                degree,
+                alteration,
+                quality,
+            } => {
+                let mut root = scale_degree(&key, degree, alteration);
+
+                if is_leading_tone_chord(&key, degree, quality) {
+                    root = root.transposed_by(Semitones(1));
+                }
+
+                Some(Chord {
+                    root,
+                    quality,
+                    inversion: self.inversion,
+                })
+            }
+            Numeral::Neapolitan => Some(Chord {
+                root: key.tonic.transposed_by(&minor(Second)),
+                quality: ChordQuality::Major,
+                inversion: self.inversion,
+            }),
+            Numeral::AugmentedSixth(_) => None,
+        }
+    }
+
+    pub fn pitch_classes(&self, key: &Key) -> PitchClassSet {
+        match self.numeral {
+            Numeral::AugmentedSixth(kind) => {
+                let tonic = self.local_key(key).tonic;
+
+                let mut intervals = vec![minor(Sixth), perfect(Unison), augmented(Fourth)];
+
+                match kind {
+                    AugmentedSixth::Italian => {}
+                    AugmentedSixth::French => intervals.push(major(Second)),
+                    AugmentedSixth::German => intervals.push(minor(Third)),
+                }
+
+                intervals
+                    .iter()
+                    .map(|interval| tonic.transposed_by(interval))
+                    .collect()
+            }
+            _ => self
+                .chord(key)
+                .expect("Only augmented sixths have no chord")
+                .pitch_classes(),
+        }
+    }
+
+    pub fn bass(&self, key: &Key) -> PitchClass {
+        match self.numeral {
+            Numeral::AugmentedSixth(_) => self.local_key(key).tonic.transposed_by(&minor(Sixth)),
+            _ => self
+                .chord(key)
+                .expect("Only augmented sixths have no chord")
+                .bass(),
+        }
+    }
+}
+
+/// Returns the pitch class on a degree of the key's scale, using the natural minor scale for minor keys.
+fn scale_degree(key: &Key, degree: IntervalRoot, alteration: Accidental) -> PitchClass {
+    key.scale().degrees()[degree.in_steps().0 as usize].transposed_by(alteration.offset)
+}
+
+/// In minor keys, diminished chords on the seventh degree are built on the raised leading tone.
+fn is_leading_tone_chord(key: &Key, degree: IntervalRoot, quality: ChordQuality) -> bool {
+    key.mode == Mode::Minor
+        && degree == Seventh
+        && matches!(
+            quality,
+            ChordQuality::Diminished
+                | ChordQuality::DiminishedSeventh
+                | ChordQuality::HalfDiminishedSeventh
+        )
+}
+
+/// The pitch classes of the key, including the raised sixth and seventh degrees of minor keys.
+fn diatonic_pitch_classes(key: &Key) -> PitchClassSet {
+    let mut classes = key.pitch_classes();
+
+    if key.mode == Mode::Minor {
+        classes.extend(
+            Scale {
+                tonic: key.tonic,
+                kind: ScaleKind::MelodicMinor,
+            }
+            .pitch_classes(),
+        );
+    }
+
+    classes
+}
+
+fn degree_numeral(chord: &Chord, key: &Key) -> Numeral {
+    let degree = IntervalClass::between(&key.tonic, &chord.root).root;
+
+    let diatonic_root = scale_degree(key, degree, NATURAL);
+
+    let mut offset = chord.root.accidental.offset - diatonic_root.accidental.offset;
+
+    if is_leading_tone_chord(key, degree, chord.quality) {
+        offset = offset - Semitones(1);
+    }
+
+    Numeral::Degree {
+        degree,
+        alteration: Accidental { offset },
+        quality: chord.quality,
+    }
+}
+
+/// Reads a chord as the dominant or leading-tone chord of one of the key's major or minor triads.
+fn secondary(chord: &Chord, key: &Key) -> Option<RomanNumeral> {
+    let (degree, interval) = match chord.quality {
+        ChordQuality::Major | ChordQuality::DominantSeventh => (Fifth, perfect(Fifth)),
+        ChordQuality::Diminished
+        | ChordQuality::DiminishedSeventh
+        | ChordQuality::HalfDiminishedSeventh => (Seventh, major(Seventh)),
+        _ => return None,
+    };
+
+    for target in [Fifth, Second, Sixth, Fourth, Third, Seventh] {
+        let mode = match triad_quality(key, target) {
+            ChordQuality::Major => Mode::Major,
+            ChordQuality::Minor => Mode::Minor,
+            _ => continue,
+        };
+
+        let tonic = scale_degree(key, target, NATURAL);
+
+        if chord.root == tonic.transposed_by(&interval) {
+            return Some(RomanNumeral {
+                numeral: Numeral::Degree {
+                    degree,
+                    alteration: NATURAL,
+                    quality: chord.quality,
+                },
+                inversion: chord.inversion,
+                of: Some(Tonicization {
+                    degree: target,
+                    alteration: NATURAL,
+                    mode,
+                }),
+            });
+        }
+    }
+
+    None
+}
+
+/// Returns the quality of the diatonic triad on a degree of the key,
+/// using the major dominant of the harmonic minor scale in minor keys.
+fn triad_quality(key: &Key, degree: IntervalRoot) -> ChordQuality {
+    if key.mode == Mode::Minor && degree == Fifth {
+        return ChordQuality::Major;
+    }
+
+    let degrees = key.scale().degrees();
+    let index = degree.in_steps().0 as usize;
+
+    let third = IntervalClass::between(&degrees[index], &degrees[(index + 2) % 7]);
+    let fifth = IntervalClass::between(&degrees[index], &degrees[(index + 4) % 7]);
+
+    match (third == major(Third), fifth == perfect(Fifth)) {
+        (true, true) => ChordQuality::Major,
+        (false, true) => ChordQuality::Minor,
+        (true, false) => ChordQuality::Augmented,
+        (false, false) => ChordQuality::Diminished,
+    }
+}
+
+/// Returns whether a chord quality is written with an upper case numeral.
+fn is_upper_case(quality: ChordQuality) -> bool {
+    matches!(
+        quality,
+        ChordQuality::Major
+            | ChordQuality::Augmented
+            | ChordQuality::DominantSeventh
+            | ChordQuality::MajorSeventh
+            | ChordQuality::AugmentedMajorSeventh
+    )
+}
+
+fn is_seventh(quality: ChordQuality) -> bool {
+    quality.intervals().len() == 4
+}
+
+fn numeral(degree: IntervalRoot, alteration: Accidental, upper_case: bool) -> String {
+    let numeral = NUMERALS[degree.in_steps().0 as usize];
+
+    let numeral = if upper_case {
+        numeral.to_string()
+    } else {
+        numeral.to_lowercase()
+    };
+
+    match alteration.offset.0 {
+        0 => numeral,
+        _ => format!("{}{}", alteration, numeral),
+    }
+}
+
+fn figures(inversion: Inversion, seventh: bool) -> &'static str {
+    match (seventh, inversion) {
+        (false, Inversion::Root) => "",
+        (false, Inversion::First) => "⁶",
+        (false, _) => "⁶₄",
+        (true, Inversion::Root) => "⁷",
+        (true, Inversion::First) => "⁶₅",
+        (true, Inversion::Second) => "⁴₃",
+        (true, Inversion::Third) => "⁴₂",
+    }
+}
+
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.numeral {
+            Numeral::Degree {
+                degree,
+                alteration,
+                quality,
+            } => {
+                let mark = match quality {
+                    ChordQuality::Diminished | ChordQuality::DiminishedSeventh => "°",
+                    ChordQuality::HalfDiminishedSeventh => "ø",
+                    ChordQuality::Augmented => "⁺",
+                    ChordQuality::MajorSeventh | ChordQuality::MinorMajorSeventh => "M",
+                    ChordQuality::AugmentedMajorSeventh => "⁺M",
+                    _ => "",
+                };
+
+                write!(
+                    f,
+                    "{}{}{}",
+                    numeral(degree, alteration, is_upper_case(quality)),
+                    mark,
+                    figures(self.inversion, is_seventh(quality)),
+                )?;
+            }
+            Numeral::Neapolitan => write!(f, "N{}", figures(self.inversion, false))?,
+            Numeral::AugmentedSixth(AugmentedSixth::Italian) => write!(f, "It⁺⁶")?,
+            Numeral::AugmentedSixth(AugmentedSixth::French) => write!(f, "Fr⁺⁶")?,
+            Numeral::AugmentedSixth(AugmentedSixth::German) => write!(f, "Ger⁺⁶")?,
+        }
+
+        if let Some(of) = self.of {
+            write!(
+                f,
+                "/{}",
+                numeral(of.degree, of.alteration, of.mode == Mode::Major)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses Roman numerals written with Unicode or ASCII figures and quality marks,
+/// such as "vii°⁷", "viio7", "V6/5/V", "V65 of V", "bVI", "N6" or "Ger+6".
+impl TryFrom<&str> for RomanNumeral {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parser = Parser::new(value);
+
+        let numeral = parser.numeral()?;
+
+        let of = if parser.eat_any(&[" of ", "/"]) {
+            let alteration = parser.accidental();
+            let (degree, upper_case) = parser.numeral_degree()?;
+
+            Some(Tonicization {
+                degree,
+                alteration,
+                mode: if upper_case { Mode::Major } else { Mode::Minor },
+            })
+        } else {
+            None
+        };
+
+        if !parser.rest.is_empty() {
+            return Err("Could not parse the end of the Roman numeral");
+        }
+
+        Ok(RomanNumeral {
+            numeral: numeral.0,
+            inversion: numeral.1,
+            of,
+        })
+    }
+}
+
+impl Parser<'_> {
+    /// Parses a numeral from I to VII, returning its degree and whether it is upper case.
+    fn numeral_degree(&mut self) -> Result<(IntervalRoot, bool), &'static str> {
+        // Try the longest numerals first, so that VII is not read as V
+        for index in (0..7).rev() {
+            let degree = IntervalRoot::from(Steps(index as Scalar));
+
+            if self.eat(NUMERALS[index]) {
+                return Ok((degree, true));
+            }
+
+            if self.eat(&NUMERALS[index].to_lowercase()) {
+                return Ok((degree, false));
+            }
+        }
+
+        Err("Could not parse Roman numeral")
+    }
+
+    /// Parses the inversion figures, returning the inversion and whether they imply a seventh chord.
+    fn figures(&mut self) -> (Inversion, bool) {
+        for (figures, inversion, seventh) in [
+            (["⁶₅", "6/5", "65"], Inversion::First, true),
+            (["⁴₃", "4/3", "43"], Inversion::Second, true),
+            (["⁴₂", "4/2", "42"], Inversion::Third, true),
+            (["⁶₄", "6/4", "64"], Inversion::Second, false),
+            (["⁷", "7", "7"], Inversion::Root, true),
+            (["⁶", "6", "6"], Inversion::First, false),
+            (["²", "2", "2"], Inversion::Third, true),
+        ] {
+            if self.eat_any(&figures) {
+                return (inversion, seventh);
+            }
+        }
+
+        (Inversion::Root, false)
+    }
+
+    fn numeral(&mut self) -> Result<(Numeral, Inversion), &'static str> {
+        if self.eat("N") {
+            let (inversion, _) = self.figures();
+
+            return Ok((Numeral::Neapolitan, inversion));
+        }
+
+        for (prefix, kind) in [
+            ("It", AugmentedSixth::Italian),
+            ("Fr", AugmentedSixth::French),
+            ("Ger", AugmentedSixth::German),
+        ] {
+            if self.eat(prefix) {
+                self.eat_any(&["⁺", "+"]);
+                self.eat_any(&["⁶", "6"]);
+
+                return Ok((Numeral::AugmentedSixth(kind), Inversion::Root));
+            }
+        }
+
+        let alteration = self.accidental();
+        let (degree, upper_case) = self.numeral_degree()?;
+
+        let diminished = self.eat_any(&["°", "o"]);
+        let half_diminished = !diminished && self.eat("ø");
+        let augmented = self.eat_any(&["⁺", "+"]);
+        let major_seventh = self.eat("M");
+
+        let (inversion, seventh) = self.figures();
+
+        let seventh = seventh || half_diminished || major_seventh;
+
+        let quality = match (upper_case, seventh) {
+            (true, false) if augmented => ChordQuality::Augmented,
+            (true, false) => ChordQuality::Major,
+            (false, false) if diminished => ChordQuality::Diminished,
+            (false, false) => ChordQuality::Minor,
+            (true, true) if augmented => ChordQuality::AugmentedMajorSeventh,
+            (true, true) if major_seventh => ChordQuality::MajorSeventh,
+            (true, true) => ChordQuality::DominantSeventh,
+            (false, true) if diminished => ChordQuality::DiminishedSeventh,
+            (false, true) if half_diminished => ChordQuality::HalfDiminishedSeventh,
+            (false, true) if major_seventh => ChordQuality::MinorMajorSeventh,
+            (false, true) => ChordQuality::MinorSeventh,
+        };
+
+        Ok((
+            Numeral::Degree {
+                degree,
+                alteration,
+                quality,
+            },
+            inversion,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{AugmentedSixth, Chord, ChordQuality, Inversion, Numeral, RomanNumeral};
+    use crate::key::Key;
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassSet, PitchClassUtils, PitchRootUtils, PitchSet};
+
+    fn key(value: &str) -> Key {
+        Key::try_from(value).unwrap()
+    }
+
+    fn analyze(chord: Chord, key_name: &str) -> String {
+        RomanNumeral::analyze(&chord, &key(key_name))
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn diatonic_chords() {
+        let chord = |root, quality, inversion| Chord {
+            root,
+            quality,
+            inversion,
+        };
+
+        assert_eq!(
+            analyze(
+                chord(C.natural(), ChordQuality::Major, Inversion::Root),
+                "C major"
+            ),
+            "I",
+        );
+        assert_eq!(
+            analyze(
+                chord(D.natural(), ChordQuality::Minor, Inversion::First),
+                "C major"
+            ),
+            "ii⁶",
+        );
+        assert_eq!(
+            analyze(
+                chord(
+                    G.natural(),
+                    ChordQuality::DominantSeventh,
+                    Inversion::Second
+                ),
+                "C major",
+            ),
+            "V⁴₃",
+        );
+        assert_eq!(
+            analyze(
+                chord(
+                    B.natural(),
+                    ChordQuality::DiminishedSeventh,
+                    Inversion::Root
+                ),
+                "C minor",
+            ),
+            "vii°⁷",
+        );
+        assert_eq!(
+            analyze(
+                chord(
+                    B.natural(),
+                    ChordQuality::HalfDiminishedSeventh,
+                    Inversion::First
+                ),
+                "C major",
+            ),
+            "viiø⁶₅",
+        );
+        assert_eq!(
+            analyze(
+                chord(B.flat(), ChordQuality::Major, Inversion::Root),
+                "C minor"
+            ),
+            "VII",
+        );
+        assert_eq!(
+            analyze(
+                chord(E.flat(), ChordQuality::Augmented, Inversion::Root),
+                "C minor"
+            ),
+            "III⁺",
+        );
+    }
+
+    #[test]
+    fn chromatic_chords() {
+        assert_eq!(
+            analyze(Chord::new(A.flat(), ChordQuality::Major), "C major"),
+            "♭VI",
+        );
+        assert_eq!(
+            analyze(
+                Chord {
+                    root: D.flat(),
+                    quality: ChordQuality::Major,
+                    inversion: Inversion::First,
+                },
+                "C minor",
+            ),
+            "N⁶",
+        );
+        assert_eq!(
+            analyze(
+                Chord::new(D.natural(), ChordQuality::DominantSeventh),
+                "C major"
+            ),
+            "V⁷/V",
+        );
+        assert_eq!(
+            analyze(Chord::new(D.natural(), ChordQuality::Major), "C minor"),
+            "V/V",
+        );
+        assert_eq!(
+            analyze(
+                Chord::new(G.sharp(), ChordQuality::DiminishedSeventh),
+                "C major"
+            ),
+            "vii°⁷/vi",
+        );
+        assert_eq!(
+            analyze(
+                Chord::new(C.natural(), ChordQuality::DominantSeventh),
+                "C major"
+            ),
+            "V⁷/IV",
+        );
+    }
+
+    #[test]
+    fn augmented_sixths() {
+        let c_major = key("C major");
+
+        let italian = PitchSet::from([A.flat().o(3), C.o(4), F.sharp().o(4)]);
+        let french = PitchSet::from([A.flat().o(3), C.o(4), D.o(4), F.sharp().o(4)]);
+
+        assert_eq!(
+            RomanNumeral::analyze_pitches(&italian, &c_major)
+                .unwrap()
+                .to_string(),
+            "It⁺⁶",
+        );
+        assert_eq!(
+            RomanNumeral::analyze_pitches(&french, &c_major)
+                .unwrap()
+                .numeral,
+            Numeral::AugmentedSixth(AugmentedSixth::French),
+        );
+    }
+
+    #[test]
+    fn pitches() {
+        let pitches = PitchSet::from([F.o(3), G.o(3), B.o(3), D.o(4)]);
+
+        assert_eq!(
+            RomanNumeral::analyze_pitches(&pitches, &key("C major"))
+                .unwrap()
+                .to_string(),
+            "V⁴₂",
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        for (value, expected) in [
+            ("I", "I"),
+            ("ii6", "ii⁶"),
+            ("V4/3", "V⁴₃"),
+            ("viio7", "vii°⁷"),
+            ("viiø65", "viiø⁶₅"),
+            ("bVI", "♭VI"),
+            ("N6", "N⁶"),
+            ("Ger+6", "Ger⁺⁶"),
+            ("V7/V", "V⁷/V"),
+            ("V65 of iii", "V⁶₅/iii"),
+            ("IM7", "IM⁷"),
+            ("III+", "III⁺"),
+            ("𝄫VII", "𝄫VII"),
+        ] {
+            assert_eq!(RomanNumeral::try_from(value).unwrap().to_string(), expected);
+        }
+
+        assert!(RomanNumeral::try_from("X").is_err());
+        assert!(RomanNumeral::try_from("V7 extra").is_err());
+    }
+
+    #[test]
+    fn spelling() {
+        let (numeral, key) = RomanNumeral::parse_in_key("vii°7/V in c").unwrap();
+
+        assert_eq!(key, self::key("C minor"));
+        assert_eq!(
+            numeral.pitch_classes(&key),
+            PitchClassSet::from([F.sharp(), A.natural(), C.natural(), E.flat()]),
+        );
+
+        let (numeral, key) = RomanNumeral::parse_in_key("bVI in C major").unwrap();
+
+        assert_eq!(
+            numeral.pitch_classes(&key),
+            PitchClassSet::from([A.flat(), C.natural(), E.flat()]),
+        );
+
+        let (numeral, key) = RomanNumeral::parse_in_key("It+6 in A minor").unwrap();
+
+        assert_eq!(numeral.bass(&key), F.natural());
+        assert_eq!(
+            numeral.pitch_classes(&key),
+            PitchClassSet::from([F.natural(), A.natural(), D.sharp()]),
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::harmony::Dyad;
+use std::hash::Hash;
+
+/// A sonority is any number of ordered values sounding together, generalizing a [Dyad].
+/// The values are kept from low to high, including any doublings.
+///
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{Dyad, Sonority};
+///
+/// let sonority = Sonority::from([3, 1, 2]);
+///
+/// assert_eq!(sonority.values, vec![1, 2, 3]);
+/// assert_eq!(sonority.dyads().len(), 3);
+/// assert!(sonority.dyads().contains(&Dyad::from((1, 3))));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Ord, PartialOrd)]
+pub struct Sonority<T: Ord + Eq + PartialEq + Hash> {
+    pub values: Vec<T>,
+}
+
+impl<T: Ord + Eq + PartialEq + Hash + Copy> Sonority<T> {
+    /// Returns every pair of values in the sonority, from low to high,
+    /// so that doubled values give repeated dyads.
+    pub fn dyads(&self) -> Vec<Dyad<T>> {
+        let mut dyads = Vec::new();
+
+        for (index, low) in self.values.iter().enumerate() {
+            for high in &self.values[index + 1..] {
+                dyads.push(Dyad::from((*low, *high)));
+            }
+        }
+
+        dyads
+    }
+}
+
+impl<T: Ord + Eq + PartialEq + Hash> From<Vec<T>> for Sonority<T> {
+    fn from(mut values: Vec<T>) -> Self {
+        values.sort();
+
+        Self { values }
+    }
+}
+
+impl<T: Ord + Eq + PartialEq + Hash, const N: usize> From<[T; N]> for Sonority<T> {
+    fn from(values: [T; N]) -> Self {
+        Self::from(Vec::from(values))
+    }
+}
+
+impl<T: Ord + Eq + PartialEq + Hash> From<Dyad<T>> for Sonority<T> {
+    fn from(dyad: Dyad<T>) -> Self {
+        Self {
+            values: vec![dyad.low, dyad.high],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::harmony::{Dyad, Sonority};
+
+    #[test]
+    fn order() {
+        assert_eq!(Sonority::from([2, 3, 1]), Sonority::from(vec![1, 2, 3]));
+        assert_eq!(Sonority::from(Dyad::from((2, 1))).values, vec![1, 2]);
+    }
+
+    #[test]
+    fn dyads() {
+        let sonority = Sonority::from([1, 2, 3, 4]);
+
+        assert_eq!(sonority.dyads().len(), 6);
+
+        // Doublings repeat the dyads of the doubled value
+        assert_eq!(
+            Sonority::from([1, 1, 2]).dyads(),
+            vec![Dyad::from((1, 1)), Dyad::from((1, 2)), Dyad::from((1, 2))]
+        );
+    }
+}
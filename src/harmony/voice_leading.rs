@@ -0,0 +1,472 @@
+use crate::harmony::{Chord, Doubling};
+use crate::pitch::PitchRoot::C;
+use crate::pitch::{
+    InRange, Pitch, PitchClass, PitchClassSet, PitchClassUtils, PitchDyad, PitchSet,
+};
+use crate::vertical::{Semitones, SemitonesFromC0};
+
+/// The movement of a single voice from one chord to the next.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Motion {
+    pub from: Pitch,
+    pub to: Pitch,
+}
+
+impl Motion {
+    /// Returns the signed distance moved, positive when going up.
+    pub fn semitones(&self) -> Semitones {
+        self.to.semitones_from_c0() - self.from.semitones_from_c0()
+    }
+}
+
+/// How the motions of all voices add up to the cost of a voice leading.
+/// See [voice leading](https://en.wikipedia.org/wiki/Voice_leading).
+#[derive(Copy, Clone, Debug)]
+pub enum VoiceLeadingCost {
+    /// The sum of the distances moved.
+    Taxicab,
+    /// The square root of the sum of the squared distances moved.
+    Euclidean,
+    /// The largest distance moved by a single voice.
+    Largest,
+    Custom(fn(&[Semitones]) -> f64),
+}
+
+impl VoiceLeadingCost {
+    pub fn of(&self, motions: &[Semitones]) -> f64 {
+        match self {
+            Self::Taxicab => motions.iter().map(|motion| motion.0.abs() as f64).sum(),
+            Self::Euclidean => motions
+                .iter()
+                .map(|motion| (motion.0 * motion.0) as f64)
+                .sum::<f64>()
+                .sqrt(),
+            Self::Largest => motions
+                .iter()
+                .map(|motion| motion.0.abs() as f64)
+                .fold(0.0, f64::max),
+            Self::Custom(cost) => cost(motions),
+        }
+    }
+}
+
+/// Restrictions on the voice leadings to consider.
+#[derive(Clone, Debug)]
+pub struct VoiceLeadingOptions {
+    pub cost: VoiceLeadingCost,
+    /// The number of voices to lead to, or that of the chord being led from if `None`.
+    /// When the numbers differ, the voices are split or merged evenly,
+    /// so that several voices may move from, or to, the same pitch.
+    pub voices: Option<usize>,
+    pub doubling: Doubling,
+    pub allow_crossing: bool,
+    /// The pitch class the lowest voice has to move to.
+    pub bass: Option<PitchClass>,
+    /// The largest distance a single voice may move.
+    pub max_motion: Semitones,
+}
+
+impl Default for VoiceLeadingOptions {
+    fn default() -> Self {
+        VoiceLeadingOptions {
+            cost: VoiceLeadingCost::Taxicab,
+            voices: None,
+            doubling: Doubling::Any,
+            allow_crossing: false,
+            bass: None,
+            max_motion: Semitones(12),
+        }
+    }
+}
+
+/// The motions of every voice from the lowest up, with their total cost.
+#[derive(Clone, PartialEq, Debug)]
+pub struct VoiceLeading {
+    pub motions: Vec<Motion>,
+    pub cost: f64,
+}
+
+impl VoiceLeading {
+    /// Returns the pitch of every voice after the motions, from the lowest up.
+    /// Voices that meet in a unison are kept apart, so the number of voices never changes.
+    pub fn target(&self) -> Vec<Pitch> {
+        self.motions.iter().map(|motion| motion.to).collect()
+    }
+}
+
+/// Finds the cheapest way to move every voice of a chord to a pitch class of the next chord,
+/// making sure that each of its pitch classes is sounded.
+/// Returns `None` if there are not enough voices, or no voicing satisfies the options.
+///
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{lead_voices, VoiceLeadingOptions};
+/// use solfege::pitch::PitchRoot::{A, C, E, F, G};
+/// use solfege::pitch::{PitchClassSet, PitchClassUtils, PitchRootUtils, PitchSet};
+///
+/// let c_major = PitchSet::from([C.o(4), E.o(4), G.o(4)]);
+/// let f_major = PitchClassSet::from([F.natural(), A.natural(), C.natural()]);
+///
+/// let leading = lead_voices(&c_major, &f_major, &VoiceLeadingOptions::default()).unwrap();
+///
+/// assert_eq!(leading.target(), vec![C.o(4), F.o(4), A.o(4)]);
+/// assert_eq!(leading.cost, 3.0);
+/// ```
+pub fn lead_voices(
+    from: &PitchSet,
+    to: &PitchClassSet,
+    options: &VoiceLeadingOptions,
+) -> Option<VoiceLeading> {
+    lead(&from.iter().copied().collect::<Vec<_>>(), to, options)
+}
+
+fn lead(from: &[Pitch], to: &PitchClassSet, options: &VoiceLeadingOptions) -> Option<VoiceLeading> {
+    let voices = match options.voices {
+        Some(count) if count != from.len() && !from.is_empty() => (0..count)
+            .map(|index| from[index * from.len() / count])
+            .collect::<Vec<_>>(),
+        _ => from.to_vec(),
+    };
+
+    let mut classes = to.clone();
+    classes.extend(options.bass);
+
+    if voices.len() < classes.len() {
+        return None;
+    }
+
+    let candidates = voices
+        .iter()
+        .enumerate()
+        .map(|(index, voice)| {
+            let octaves = options.max_motion.0 / 12 + 2;
+
+            let range = PitchDyad::from((
+                C.o(voice.octave.octaves_from_c0.0 - octaves),
+                C.o(voice.octave.octaves_from_c0.0 + octaves),
+            ));
+
+            let allowed = match options.bass {
+                Some(bass) if index == 0 => PitchClassSet::from([bass]),
+                _ => classes.clone(),
+            };
+
+            allowed
+                .in_range(&range)
+                .into_iter()
+                .filter(|pitch| {
+                    (pitch.semitones_from_c0() - voice.semitones_from_c0())
+                        .0
+                        .abs()
+                        <= options.max_motion.0
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut best: Option<VoiceLeading> = None;
+
+    search(
+        &voices,
+        &candidates,
+        &classes,
+        options,
+        &mut vec![],
+        &mut best,
+    );
+
+    best
+}
+
+/// Leads the voices through a progression of chords, one chord at a time, keeping each chord's bass in the lowest voice.
+/// Returns `None` if some chord cannot be reached.
+///
+/// # Example
+///
+/// ```
+/// use solfege::harmony::{lead_progression, Chord, ChordQuality, VoiceLeadingOptions};
+/// use solfege::pitch::PitchRoot::{C, E, F, G};
+/// use solfege::pitch::{PitchClassUtils, PitchRootUtils, PitchSet};
+///
+/// let start = PitchSet::from([C.o(3), G.o(3), C.o(4), E.o(4)]);
+///
+/// let chords = [
+///     Chord::new(F.natural(), ChordQuality::Major),
+///     Chord::new(G.natural(), ChordQuality::DominantSeventh),
+///     Chord::new(C.natural(), ChordQuality::Major),
+/// ];
+///
+/// let leadings = lead_progression(&start, &chords, &VoiceLeadingOptions::default()).unwrap();
+///
+/// let bass_line = leadings
+///     .iter()
+///     .map(|leading| leading.motions[0].to)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(bass_line, vec![F.o(3), G.o(3), C.o(4)]);
+/// ```
+pub fn lead_progression(
+    start: &PitchSet,
+    chords: &[Chord],
+    options: &VoiceLeadingOptions,
+) -> Option<Vec<VoiceLeading>> {
+    let mut current = start.iter().copied().collect::<Vec<_>>();
+    let mut leadings = vec![];
+
+    for chord in chords {
+        let options = VoiceLeadingOptions {
+            bass: Some(chord.bass()),
+            ..options.clone()
+        };
+
+        let leading = lead(&current, &chord.pitch_classes(), &options)?;
+
+        current = leading.target();
+        leadings.push(leading);
+    }
+
+    Some(leadings)
+}
+
+fn search(
+    voices: &[Pitch],
+    candidates: &[Vec<Pitch>],
+    classes: &PitchClassSet,
+    options: &VoiceLeadingOptions,
+    chosen: &mut Vec<Pitch>,
+    best: &mut Option<VoiceLeading>,
+) {
+    if chosen.len() == voices.len() {
+        if !is_complete(chosen, classes) || !options.doubling.allows(&chosen[0], &chosen[1..]) {
+            return;
+        }
+
+        let motions = voices
+            .iter()
+            .zip(chosen.iter())
+            .map(|(from, to)| Motion {
+                from: *from,
+                to: *to,
+            })
+            .collect::<Vec<_>>();
+
+        let cost = options.cost.of(&motions
+            .iter()
+            .map(|motion| motion.semitones())
+            .collect::<Vec<_>>());
+
+        if best.as_ref().is_none_or(|best| cost < best.cost) {
+            *best = Some(VoiceLeading { motions, cost });
+        }
+
+        return;
+    }
+
+    for candidate in &candidates[chosen.len()] {
+        let is_crossing = chosen
+            .last()
+            .is_some_and(|previous| candidate.semitones_from_c0() < previous.semitones_from_c0());
+
+        // The bass stays the lowest voice even when other voices may cross
+        let is_below_bass = options.bass.is_some()
+            && chosen
+                .first()
+                .is_some_and(|bass| candidate.semitones_from_c0() < bass.semitones_from_c0());
+
+        if (is_crossing && !options.allow_crossing) || is_below_bass {
+            continue;
+        }
+
+        chosen.push(*candidate);
+        search(voices, candidates, classes, options, chosen, best);
+        chosen.pop();
+    }
+}
+
+fn is_complete(pitches: &[Pitch], classes: &PitchClassSet) -> bool {
+    classes
+        .iter()
+        .all(|class| pitches.iter().any(|pitch| pitch.class == *class))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{
+        lead_progression, lead_voices, Chord, ChordQuality, Doubling, Motion, VoiceLeadingCost,
+        VoiceLeadingOptions,
+    };
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassSet, PitchClassUtils, PitchRootUtils, PitchSet};
+    use crate::vertical::Semitones;
+
+    fn g7() -> PitchClassSet {
+        PitchClassSet::from([G.natural(), B.natural(), D.natural(), F.natural()])
+    }
+
+    fn c_major() -> PitchClassSet {
+        PitchClassSet::from([C.natural(), E.natural(), G.natural()])
+    }
+
+    #[test]
+    fn resolves_dominant_seventh() {
+        let from = PitchSet::from([G.o(3), B.o(3), D.o(4), F.o(4)]);
+
+        let leading = lead_voices(&from, &c_major(), &VoiceLeadingOptions::default()).unwrap();
+
+        assert_eq!(
+            leading.motions[1],
+            Motion {
+                from: B.o(3),
+                to: C.o(4)
+            }
+        );
+        assert_eq!(
+            leading.motions[3],
+            Motion {
+                from: F.o(4),
+                to: E.o(4)
+            }
+        );
+        assert_eq!(leading.motions[3].semitones(), Semitones(-1));
+        assert_eq!(leading.cost, 4.0);
+    }
+
+    #[test]
+    fn costs() {
+        let motions = [Semitones(3), Semitones(-4)];
+
+        assert_eq!(VoiceLeadingCost::Taxicab.of(&motions), 7.0);
+        assert_eq!(VoiceLeadingCost::Euclidean.of(&motions), 5.0);
+        assert_eq!(VoiceLeadingCost::Largest.of(&motions), 4.0);
+        assert_eq!(
+            VoiceLeadingCost::Custom(|motions| motions.len() as f64).of(&motions),
+            2.0
+        );
+    }
+
+    #[test]
+    fn fixed_bass() {
+        let from = PitchSet::from([C.o(3), G.o(3), C.o(4), E.o(4)]);
+
+        let options = VoiceLeadingOptions {
+            bass: Some(B.natural()),
+            ..VoiceLeadingOptions::default()
+        };
+
+        let leading = lead_voices(&from, &g7(), &options).unwrap();
+
+        assert_eq!(leading.motions[0].to, B.o(2));
+        assert_eq!(leading.target(), vec![B.o(2), G.o(3), D.o(4), F.o(4)]);
+    }
+
+    #[test]
+    fn crossing() {
+        let from = PitchSet::from([D.o(4), E.o(4)]);
+        let to = PitchClassSet::from([C.natural(), D.natural()]);
+
+        // Moving the lower voice is expensive
+        let cost = VoiceLeadingCost::Custom(|motions| {
+            10.0 * motions[0].0.abs() as f64 + motions[1].0.abs() as f64
+        });
+
+        let options = VoiceLeadingOptions {
+            cost,
+            ..VoiceLeadingOptions::default()
+        };
+
+        assert_eq!(
+            lead_voices(&from, &to, &options).unwrap().target(),
+            vec![D.o(4), C.o(5)]
+        );
+
+        let crossing = VoiceLeadingOptions {
+            allow_crossing: true,
+            ..options
+        };
+
+        assert_eq!(
+            lead_voices(&from, &to, &crossing).unwrap().motions[1].to,
+            C.o(4)
+        );
+    }
+
+    #[test]
+    fn doubling_and_voice_count() {
+        let from = PitchSet::from([C.o(4), E.o(4), G.o(4)]);
+
+        let forbidden = VoiceLeadingOptions {
+            doubling: Doubling::Forbidden,
+            ..VoiceLeadingOptions::default()
+        };
+
+        assert!(lead_voices(&from, &g7(), &VoiceLeadingOptions::default()).is_none());
+        assert!(lead_voices(
+            &PitchSet::from([C.o(4), E.o(4), G.o(4), C.o(5)]),
+            &PitchClassSet::from([F.natural(), A.natural(), C.natural()]),
+            &forbidden
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn unisons_keep_their_voices() {
+        let from = PitchSet::from([E.o(4), F.o(4)]);
+
+        let leading = lead_voices(
+            &from,
+            &PitchClassSet::from([E.natural()]),
+            &VoiceLeadingOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(leading.target(), vec![E.o(4), E.o(4)]);
+
+        // The D moves to a unison with the C or the E, and both voices carry on to the next chord
+        let start = PitchSet::from([C.o(4), D.o(4), E.o(4), G.o(4)]);
+
+        let chords = [
+            Chord::new(C.natural(), ChordQuality::Major),
+            Chord::new(F.natural(), ChordQuality::Major),
+        ];
+
+        let leadings = lead_progression(&start, &chords, &VoiceLeadingOptions::default()).unwrap();
+
+        let target = leadings[0].target();
+
+        assert!(target.windows(2).any(|pair| pair[0] == pair[1]));
+        assert_eq!(leadings[1].motions.len(), 4);
+    }
+
+    #[test]
+    fn fixed_number_of_voices() {
+        let from = PitchSet::from([C.o(4), E.o(4), G.o(4)]);
+
+        let options = VoiceLeadingOptions {
+            voices: Some(4),
+            ..VoiceLeadingOptions::default()
+        };
+
+        let leading = lead_voices(&from, &g7(), &options).unwrap();
+
+        assert_eq!(leading.motions.len(), 4);
+        assert_eq!(leading.target().into_iter().collect::<PitchSet>().len(), 4);
+
+        let fewer = VoiceLeadingOptions {
+            voices: Some(2),
+            ..VoiceLeadingOptions::default()
+        };
+
+        assert_eq!(
+            lead_voices(
+                &from,
+                &PitchClassSet::from([D.natural(), F.natural()]),
+                &fewer
+            )
+            .unwrap()
+            .motions
+            .len(),
+            2
+        );
+    }
+}
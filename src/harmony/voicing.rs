@@ -0,0 +1,364 @@
+use crate::pitch::{InRange, Pitch, PitchClassSet, PitchDyad, PitchSet};
+use crate::vertical::{Octaves, Semitones, SemitonesFromC0, TransposedBy};
+use std::collections::BTreeMap;
+
+/// How the voices above the bass are arranged.
+/// See [voicing](https://en.wikipedia.org/wiki/Voicing_(music)).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Position {
+    /// No chord tone fits between two adjacent upper voices.
+    Close,
+    /// Not in close position, but with no more than an octave between adjacent upper voices.
+    Open,
+    /// A close position voicing with the second voice from the top dropped by an octave.
+    Drop2,
+    /// A close position voicing with the third voice from the top dropped by an octave.
+    Drop3,
+    /// A close position voicing with the second and fourth voices from the top dropped by an octave.
+    Drop2And4,
+    /// More than an octave between some adjacent upper voices.
+    Spread,
+}
+
+/// Which pitch classes may appear in more than one voice.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Doubling {
+    Forbidden,
+    Bass,
+    Any,
+    Only(PitchClassSet),
+}
+
+/// Restrictions on the voicings to generate.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct VoicingOptions {
+    /// The number of voices, including the bass.
+    pub voices: usize,
+    /// Any position when `None`.
+    pub position: Option<Position>,
+    /// The largest distance between the bass and the top voice.
+    pub max_span: Option<Semitones>,
+    pub doubling: Doubling,
+}
+
+impl Doubling {
+    /// Checks whether every doubled pitch class of a voicing may be doubled.
+    pub fn allows(&self, bass: &Pitch, upper: &[Pitch]) -> bool {
+        let mut counts = BTreeMap::new();
+
+        for pitch in upper.iter().chain([bass]) {
+            *counts.entry(pitch.class).or_insert(0) += 1;
+        }
+
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .all(|(class, _)| match self {
+                Self::Forbidden => false,
+                Self::Bass => class == bass.class,
+                Self::Any => true,
+                Self::Only(classes) => classes.contains(&class),
+            })
+    }
+}
+
+impl Default for VoicingOptions {
+    fn default() -> Self {
+        VoicingOptions {
+            voices: 4,
+            position: None,
+            max_span: None,
+            doubling: Doubling::Any,
+        }
+    }
+}
+
+pub trait Voicings {
+    /// Returns every voicing over the bass within the range that contains all the pitch classes.
+    fn voicings(&self, bass: &Pitch, range: &PitchDyad, options: &VoicingOptions) -> Vec<PitchSet>;
+
+    /// Returns the voicings sorted by a ranking, lowest first.
+    fn voicings_ranked_by<K: Ord>(
+        &self,
+        bass: &Pitch,
+        range: &PitchDyad,
+        options: &VoicingOptions,
+        rank: impl Fn(&PitchSet) -> K,
+    ) -> Vec<PitchSet> {
+        let mut voicings = self.voicings(bass, range, options);
+
+        voicings.sort_by_cached_key(|voicing| rank(voicing));
+
+        voicings
+    }
+}
+
+/// Voices a chord's pitch classes over a bass pitch.
+/// The bass' own pitch class is always part of the chord.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::harmony::{Position, Voicings, VoicingOptions};
+/// use solfege::pitch::PitchRoot::{B, C, D, F, G};
+/// use solfege::pitch::{PitchClassSet, PitchClassUtils, PitchDyad, PitchRootUtils, PitchSet};
+/// use solfege::vertical::SemitonesFromC0;
+///
+/// let g7 = PitchClassSet::from([G.natural(), B.natural(), D.natural(), F.natural()]);
+/// let range = PitchDyad::from((C.o(2), C.o(6)));
+///
+/// let drop_2 = VoicingOptions {
+///     position: Some(Position::Drop2),
+///     ..VoicingOptions::default()
+/// };
+///
+/// // Keep the lowest voicing
+/// let voicings = g7.voicings_ranked_by(&G.o(2), &range, &drop_2, |voicing| {
+///     voicing.iter().last().unwrap().semitones_from_c0()
+/// });
+///
+/// assert_eq!(voicings[0], PitchSet::from([G.o(2), D.o(3), B.o(3), F.o(4)]));
+/// ```
+impl Voicings for PitchClassSet {
+    fn voicings(&self, bass: &Pitch, range: &PitchDyad, options: &VoicingOptions) -> Vec<PitchSet> {
+        if options.voices == 0 || *bass < range.low || *bass > range.high {
+            return vec![];
+        }
+
+        let mut classes = self.clone();
+        classes.insert(bass.class);
+
+        let candidates = classes
+            .in_range(range)
+            .into_iter()
+            .filter(|pitch| semitones(pitch) > semitones(bass))
+            .collect::<Vec<_>>();
+
+        combinations(&candidates, options.voices - 1)
+            .into_iter()
+            .filter(|upper| {
+                is_complete(bass, upper, &classes)
+                    && options.doubling.allows(bass, upper)
+                    && options.max_span.is_none_or(|max_span| {
+                        upper
+                            .last()
+                            .is_none_or(|top| semitones(top) - semitones(bass) <= max_span)
+                    })
+                    && options
+                        .position
+                        .is_none_or(|position| is_in_position(upper, &classes, position))
+            })
+            .map(|upper| upper.into_iter().chain([*bass]).collect())
+            .collect()
+    }
+}
+
+fn semitones(pitch: &Pitch) -> Semitones {
+    pitch.semitones_from_c0()
+}
+
+/// Returns all ascending selections of a number of candidates.
+fn combinations(candidates: &[Pitch], count: usize) -> Vec<Vec<Pitch>> {
+    if count == 0 {
+        return vec![vec![]];
+    }
+
+    (0..candidates.len())
+        .flat_map(|index| {
+            combinations(&candidates[index + 1..], count - 1)
+                .into_iter()
+                .map(move |rest| [candidates[index]].into_iter().chain(rest).collect())
+        })
+        .collect()
+}
+
+fn is_complete(bass: &Pitch, upper: &[Pitch], classes: &PitchClassSet) -> bool {
+    classes
+        .iter()
+        .all(|class| bass.class == *class || upper.iter().any(|pitch| pitch.class == *class))
+}
+
+fn is_close(upper: &[Pitch], classes: &PitchClassSet) -> bool {
+    upper.windows(2).all(|pair| {
+        semitones(&pair[1]) - semitones(&pair[0]) <= Semitones(12)
+            && classes.in_range(&PitchDyad::from((pair[0], pair[1]))).len() == 2
+    })
+}
+
+/// Checks whether raising the lowest voices by an octave, so that they end up at the given positions
+/// counted from the top, results in close position.
+fn is_dropped(upper: &[Pitch], classes: &PitchClassSet, drops: &[usize]) -> bool {
+    if drops.iter().any(|drop| *drop > upper.len()) {
+        return false;
+    }
+
+    let raised = upper[..drops.len()]
+        .iter()
+        .map(|pitch| Pitch {
+            octave: pitch.octave.transposed_by(Octaves(1)),
+            class: pitch.class,
+        })
+        .collect::<Vec<_>>();
+
+    let mut closed = raised
+        .iter()
+        .chain(&upper[drops.len()..])
+        .copied()
+        .collect::<Vec<_>>();
+    closed.sort();
+
+    // The lowest raised voice was dropped from the lowest position
+    let mut positions = drops.to_vec();
+    positions.sort_by_key(|position| std::cmp::Reverse(*position));
+
+    is_close(&closed, classes)
+        && raised
+            .iter()
+            .zip(positions)
+            .all(|(pitch, position)| closed[closed.len() - position] == *pitch)
+}
+
+fn is_in_position(upper: &[Pitch], classes: &PitchClassSet, position: Position) -> bool {
+    let is_spread = upper
+        .windows(2)
+        .any(|pair| semitones(&pair[1]) - semitones(&pair[0]) > Semitones(12));
+
+    match position {
+        Position::Close => is_close(upper, classes),
+        Position::Open => !is_spread && !is_close(upper, classes),
+        Position::Drop2 => is_dropped(upper, classes, &[2]),
+        Position::Drop3 => is_dropped(upper, classes, &[3]),
+        Position::Drop2And4 => is_dropped(upper, classes, &[2, 4]),
+        Position::Spread => is_spread,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::harmony::{Doubling, Position, VoicingOptions, Voicings};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassSet, PitchClassUtils, PitchDyad, PitchRootUtils, PitchSet};
+    use crate::vertical::Semitones;
+
+    fn c_major() -> PitchClassSet {
+        PitchClassSet::from([C.natural(), E.natural(), G.natural()])
+    }
+
+    fn c_major_seventh() -> PitchClassSet {
+        PitchClassSet::from([C.natural(), E.natural(), G.natural(), B.natural()])
+    }
+
+    fn options(position: Position) -> VoicingOptions {
+        VoicingOptions {
+            position: Some(position),
+            ..VoicingOptions::default()
+        }
+    }
+
+    #[test]
+    fn close_position() {
+        let voicings = c_major().voicings(
+            &C.o(3),
+            &PitchDyad::from((C.o(3), C.o(4))),
+            &options(Position::Close),
+        );
+
+        assert_eq!(
+            voicings,
+            vec![PitchSet::from([C.o(3), E.o(3), G.o(3), C.o(4)])]
+        );
+    }
+
+    #[test]
+    fn open_position() {
+        let voicings = c_major().voicings(
+            &C.o(3),
+            &PitchDyad::from((C.o(3), G.o(4))),
+            &options(Position::Open),
+        );
+
+        assert!(voicings.contains(&PitchSet::from([C.o(3), G.o(3), E.o(4), G.o(4)])));
+        assert!(!voicings.contains(&PitchSet::from([C.o(3), E.o(3), G.o(3), C.o(4)])));
+    }
+
+    #[test]
+    fn drop_voicings() {
+        let range = PitchDyad::from((C.o(1), C.o(5)));
+
+        // Dropping G3 from E3 G3 B3
+        let drop_2 = c_major_seventh().voicings(&C.o(2), &range, &options(Position::Drop2));
+        assert!(drop_2.contains(&PitchSet::from([C.o(2), G.o(2), E.o(3), B.o(3)])));
+        assert!(!drop_2.contains(&PitchSet::from([C.o(2), E.o(3), G.o(3), B.o(3)])));
+
+        // Dropping E3 from E3 G3 B3
+        let drop_3 = c_major_seventh().voicings(&C.o(2), &range, &options(Position::Drop3));
+        assert!(drop_3.contains(&PitchSet::from([C.o(2), E.o(2), G.o(3), B.o(3)])));
+        assert!(!drop_3.contains(&PitchSet::from([C.o(2), G.o(2), E.o(3), B.o(3)])));
+
+        // Dropping C3 and G3 from C3 E3 G3 B3
+        let drop_2_and_4 = c_major_seventh().voicings(
+            &C.o(1),
+            &range,
+            &VoicingOptions {
+                voices: 5,
+                ..options(Position::Drop2And4)
+            },
+        );
+        assert!(drop_2_and_4.contains(&PitchSet::from([C.o(1), C.o(2), G.o(2), E.o(3), B.o(3)])));
+    }
+
+    #[test]
+    fn doubling() {
+        let range = PitchDyad::from((C.o(3), C.o(5)));
+
+        let forbidden = VoicingOptions {
+            doubling: Doubling::Forbidden,
+            ..VoicingOptions::default()
+        };
+        assert!(c_major().voicings(&C.o(3), &range, &forbidden).is_empty());
+        assert!(!c_major_seventh()
+            .voicings(&C.o(3), &range, &forbidden)
+            .is_empty());
+
+        let bass = VoicingOptions {
+            doubling: Doubling::Bass,
+            ..VoicingOptions::default()
+        };
+        assert!(c_major()
+            .voicings(&E.o(3), &range, &bass)
+            .iter()
+            .all(|voicing| voicing
+                .iter()
+                .filter(|pitch| pitch.class == E.natural())
+                .count()
+                == 2));
+    }
+
+    #[test]
+    fn voices_and_span() {
+        let range = PitchDyad::from((C.o(3), C.o(6)));
+
+        let options = VoicingOptions {
+            voices: 5,
+            max_span: Some(Semitones(19)),
+            ..VoicingOptions::default()
+        };
+
+        let voicings = c_major().voicings(&C.o(3), &range, &options);
+
+        assert!(!voicings.is_empty());
+        assert!(voicings.iter().all(|voicing| voicing.len() == 5));
+        assert!(voicings
+            .iter()
+            .all(|voicing| voicing.iter().last().unwrap() <= &G.o(4)));
+    }
+
+    #[test]
+    fn bass_out_of_range() {
+        let range = PitchDyad::from((C.o(3), C.o(5)));
+
+        assert!(c_major()
+            .voicings(&C.o(2), &range, &VoicingOptions::default())
+            .is_empty());
+    }
+}
@@ -1,8 +1,10 @@
 use crate::interval::IntervalQuality::{Imperfect, Perfectable};
 use crate::interval::IntervalRoot::Unison;
 use crate::interval::{GetIntervalQuality, IntervalQuality, IntervalRoot, Inverted, IsPerfect};
-use crate::pitch::{PitchClassDyad, PitchRootDyad};
-use crate::vertical::{InOctaves, InSemitones, InSteps, Semitones, SemitonesFromC, Steps};
+use crate::pitch::{PitchClass, PitchClassDyad, PitchRootDyad};
+use crate::vertical::{
+    InOctaves, InSemitones, InSteps, Semitones, SemitonesFromC, Steps, StepsFromC,
+};
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, Sub};
 
@@ -27,6 +29,37 @@ pub struct IntervalClass {
     pub semitones: Semitones,
 }
 
+impl IntervalClass {
+    /// Returns the interval upward from one pitch class to another, wrapping around the octave.
+    ///
+    /// Unlike converting a [PitchClassDyad], this keeps the given order of the pitch classes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::interval::{augmented, minor, IntervalClass};
+    /// use solfege::interval::IntervalRoot::{Second, Sixth};
+    /// use solfege::pitch::PitchRoot::{B, C, E};
+    /// use solfege::pitch::PitchRootUtils;
+    ///
+    /// assert_eq!(IntervalClass::between(&B.flat(), &C.sharp()), augmented(Second));
+    /// assert_eq!(IntervalClass::between(&E.natural(), &C.natural()), minor(Sixth));
+    /// ```
+    pub fn between(from: &PitchClass, to: &PitchClass) -> Self {
+        let mut semitones = to.semitones_from_c() - from.semitones_from_c();
+
+        // If the root has wrapped around, add another 12 semitones
+        if to.root < from.root {
+            semitones = semitones + Semitones(12);
+        }
+
+        IntervalClass {
+            root: IntervalRoot::from(to.steps_from_c() - from.steps_from_c()),
+            semitones,
+        }
+    }
+}
+
 impl InSteps for IntervalClass {
     fn in_steps(&self) -> Steps {
         self.root.in_steps()
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod interval;
 mod interval_class;
 mod interval_quality;
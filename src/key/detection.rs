@@ -0,0 +1,317 @@
+use crate::key::{Key, KeySignature, Mode};
+use crate::pitch::{Pitch, PitchSet};
+use std::cmp::Ordering;
+
+/// A key profile for the [Krumhansl–Schmuckler](https://en.wikipedia.org/wiki/Key_finding) key-finding algorithm,
+/// giving a weight to each of the 12 pitch classes above the tonic.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum KeyProfile {
+    /// Probe tone ratings from Krumhansl and Kessler (1982).
+    KrumhanslKessler,
+    /// The revised profile from Temperley (1999).
+    Temperley,
+    /// Pitch class frequencies in the Essen folksong collection, from Aarden (2003).
+    AardenEssen,
+}
+
+impl KeyProfile {
+    /// Returns the weights of the 12 pitch classes, starting from the tonic.
+    pub fn weights(&self, mode: Mode) -> [f64; 12] {
+        match (self, mode) {
+            (Self::KrumhanslKessler, Mode::Major) => [
+                6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+            ],
+            (Self::KrumhanslKessler, Mode::Minor) => [
+                6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+            ],
+            (Self::Temperley, Mode::Major) => {
+                [5.0, 2.0, 3.5, 2.0, 4.5, 4.0, 2.0, 4.5, 2.0, 3.5, 1.5, 4.0]
+            }
+            (Self::Temperley, Mode::Minor) => {
+                [5.0, 2.0, 3.5, 4.5, 2.0, 4.0, 2.0, 4.5, 3.5, 2.0, 1.5, 4.0]
+            }
+            (Self::AardenEssen, Mode::Major) => [
+                17.7661, 0.145624, 14.9265, 0.160186, 19.8049, 11.3587, 0.291248, 22.062, 0.145624,
+                8.15494, 0.232998, 4.95122,
+            ],
+            (Self::AardenEssen, Mode::Minor) => [
+                18.2648, 0.737619, 14.0499, 16.8599, 0.702494, 14.4362, 0.702494, 18.6161, 4.56621,
+                1.93186, 7.37619, 1.75623,
+            ],
+        }
+    }
+}
+
+/// A candidate key, with the correlation between its profile and the pitch content.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct KeyEstimate {
+    pub key: Key,
+    pub correlation: f64,
+}
+
+pub trait EstimateKeys {
+    /// Returns all 24 major and minor keys, ordered from most to least likely.
+    fn estimate_keys(&self, profile: KeyProfile) -> Vec<KeyEstimate>;
+}
+
+/// Every pitch in the set counts once.
+///
+/// # Example
+///
+/// ```
+/// use solfege::key::{EstimateKeys, Key, KeyProfile};
+/// use solfege::pitch::PitchClassUtils;
+/// use solfege::pitch::PitchRoot::{B, D, E, G};
+/// use solfege::pitch::PitchSet;
+///
+/// let pitches = PitchSet::from([G.o(3), B.o(3), D.o(4), E.o(4), G.o(4)]);
+///
+/// let estimates = pitches.estimate_keys(KeyProfile::KrumhanslKessler);
+///
+/// assert_eq!(estimates[0].key, Key::try_from("G major").unwrap());
+/// ```
+impl EstimateKeys for PitchSet {
+    fn estimate_keys(&self, profile: KeyProfile) -> Vec<KeyEstimate> {
+        self.iter()
+            .map(|pitch| (*pitch, 1.0))
+            .collect::<Vec<_>>()
+            .estimate_keys(profile)
+    }
+}
+
+/// Every pitch in the sequence counts once, so repeated pitches weigh more.
+impl EstimateKeys for [Pitch] {
+    fn estimate_keys(&self, profile: KeyProfile) -> Vec<KeyEstimate> {
+        self.iter()
+            .map(|pitch| (*pitch, 1.0))
+            .collect::<Vec<_>>()
+            .estimate_keys(profile)
+    }
+}
+
+/// Every pitch is weighted, for example by its duration.
+impl EstimateKeys for [(Pitch, f64)] {
+    fn estimate_keys(&self, profile: KeyProfile) -> Vec<KeyEstimate> {
+        let mut distribution = [0.0; 12];
+
+        for (pitch, weight) in self {
+            distribution[pitch.class.integer_notation() as usize] += weight;
+        }
+
+        estimate_keys_from_distribution(&distribution, profile)
+    }
+}
+
+/// Returns all 24 major and minor keys, ordered by the correlation of their profile
+/// with a distribution of weights over the 12 pitch classes, starting from C.
+pub fn estimate_keys_from_distribution(
+    distribution: &[f64; 12],
+    profile: KeyProfile,
+) -> Vec<KeyEstimate> {
+    let mut estimates = vec![];
+
+    for mode in [Mode::Major, Mode::Minor] {
+        let weights = profile.weights(mode);
+
+        for tonic in 0..12 {
+            let rotated =
+                core::array::from_fn::<f64, 12, _>(|index| weights[(index + 12 - tonic) % 12]);
+
+            estimates.push(KeyEstimate {
+                key: key_on(tonic as i32, mode),
+                correlation: correlation(distribution, &rotated),
+            });
+        }
+    }
+
+    estimates.sort_by(|a, b| {
+        b.correlation
+            .partial_cmp(&a.correlation)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    estimates
+}
+
+/// Estimates the keys of consecutive windows of a weighted sequence of pitches,
+/// so that modulations can be tracked.
+/// Windows are `window` pitches long and start every `hop` pitches.
+/// If the hops do not reach the end, a last window ends on the last pitch, so that no pitch is left out.
+/// A sequence shorter than a window is estimated as a whole.
+/// Each window's estimates are returned with the index of its first pitch.
+///
+/// Panics if the window or hop is zero.
+///
+/// # Example
+///
+/// ```
+/// use solfege::key::{windowed_key_estimates, Key, KeyProfile};
+/// use solfege::pitch::PitchRoot::*;
+/// use solfege::pitch::{PitchClassUtils, PitchRootUtils};
+///
+/// let melody = [
+///     C.o(4), E.o(4), G.o(4), F.o(4), D.o(4), B.o(3), C.o(4), G.o(4),
+///     F.sharp().o(4), A.o(4), D.o(5), C.o(5), B.o(4), A.o(4), F.sharp().o(4), G.o(4),
+/// ].map(|pitch| (pitch, 1.0));
+///
+/// let windows = windowed_key_estimates(&melody, 8, 8, KeyProfile::Temperley);
+///
+/// assert_eq!(windows.len(), 2);
+/// assert_eq!(windows[0].0, 0);
+/// assert_eq!(windows[0].1[0].key, Key::try_from("C major").unwrap());
+/// assert_eq!(windows[1].0, 8);
+/// assert_eq!(windows[1].1[0].key, Key::try_from("G major").unwrap());
+/// ```
+pub fn windowed_key_estimates(
+    pitches: &[(Pitch, f64)],
+    window: usize,
+    hop: usize,
+    profile: KeyProfile,
+) -> Vec<(usize, Vec<KeyEstimate>)> {
+    assert!(window > 0 && hop > 0, "Window and hop must be positive");
+
+    if pitches.len() <= window {
+        return vec![(0, pitches.estimate_keys(profile))];
+    }
+
+    let last = pitches.len() - window;
+
+    let mut starts = (0..=last).step_by(hop).collect::<Vec<_>>();
+
+    if starts.last() != Some(&last) {
+        starts.push(last);
+    }
+
+    starts
+        .into_iter()
+        .map(|start| (start, pitches[start..start + window].estimate_keys(profile)))
+        .collect()
+}
+
+/// Returns the most simply spelled key on a tonic in integer notation.
+fn key_on(tonic: i32, mode: Mode) -> Key {
+    let fifths = (tonic * 7).rem_euclid(12);
+
+    let signature = match mode {
+        Mode::Major => KeySignature { fifths },
+        Mode::Minor => KeySignature { fifths: fifths - 3 },
+    };
+
+    Key::from_signature(signature, mode).simplest_spelling()
+}
+
+/// Returns the Pearson correlation coefficient of two distributions.
+fn correlation(a: &[f64; 12], b: &[f64; 12]) -> f64 {
+    let mean_a = a.iter().sum::<f64>() / 12.0;
+    let mean_b = b.iter().sum::<f64>() / 12.0;
+
+    let mut covariance = 0.0;
+    let mut variance_a = 0.0;
+    let mut variance_b = 0.0;
+
+    for index in 0..12 {
+        covariance += (a[index] - mean_a) * (b[index] - mean_b);
+        variance_a += (a[index] - mean_a).powi(2);
+        variance_b += (b[index] - mean_b).powi(2);
+    }
+
+    if variance_a == 0.0 || variance_b == 0.0 {
+        return 0.0;
+    }
+
+    covariance / (variance_a * variance_b).sqrt()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{key_on, windowed_key_estimates, EstimateKeys, KeyProfile};
+    use crate::key::{Key, Mode};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{Pitch, PitchClassUtils, PitchRootUtils};
+
+    fn key(value: &str) -> Key {
+        Key::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn spelling_of_keys() {
+        assert_eq!(key_on(1, Mode::Major), key("D♭ major"));
+        assert_eq!(key_on(3, Mode::Major), key("E♭ major"));
+        assert_eq!(key_on(6, Mode::Major), key("F♯ major"));
+        assert_eq!(key_on(10, Mode::Minor), key("B♭ minor"));
+        assert_eq!(key_on(8, Mode::Minor), key("G♯ minor"));
+    }
+
+    #[test]
+    fn all_profiles_find_the_key() {
+        let melody: Vec<Pitch> = vec![
+            A.o(3),
+            C.o(4),
+            E.o(4),
+            A.o(4),
+            G.sharp().o(4),
+            B.o(4),
+            E.o(4),
+            D.o(4),
+            C.o(4),
+            B.o(3),
+            A.o(3),
+        ];
+
+        for profile in [
+            KeyProfile::KrumhanslKessler,
+            KeyProfile::Temperley,
+            KeyProfile::AardenEssen,
+        ] {
+            let estimates = melody.estimate_keys(profile);
+
+            assert_eq!(estimates.len(), 24);
+            assert_eq!(estimates[0].key, key("A minor"));
+            assert!(estimates[0].correlation > estimates[1].correlation);
+        }
+    }
+
+    #[test]
+    fn weighted_by_duration() {
+        let pitches = [
+            (D.o(4), 4.0),
+            (F.sharp().o(4), 2.0),
+            (A.o(4), 2.0),
+            (C.o(4), 0.5),
+        ];
+
+        let estimates = pitches.estimate_keys(KeyProfile::KrumhanslKessler);
+
+        assert_eq!(estimates[0].key, key("D major"));
+    }
+
+    #[test]
+    fn trailing_window() {
+        let scale = [C.o(4), D.o(4), E.o(4), F.o(4), G.o(4), A.o(4), B.o(4)];
+
+        let mut pitches = scale
+            .iter()
+            .chain(scale.iter())
+            .map(|pitch| (*pitch, 1.0))
+            .collect::<Vec<_>>();
+
+        // The last notes move towards F major, and would be dropped by the hops alone
+        pitches.extend([F.o(4), A.o(4), B.flat().o(4), C.o(5), F.o(5)].map(|pitch| (pitch, 1.0)));
+
+        let windows = windowed_key_estimates(&pitches, 8, 8, KeyProfile::Temperley);
+
+        assert_eq!(
+            windows.iter().map(|(start, _)| *start).collect::<Vec<_>>(),
+            vec![0, 8, 11]
+        );
+        assert_eq!(
+            windows[2].1[0].key,
+            pitches[11..].estimate_keys(KeyProfile::Temperley)[0].key
+        );
+
+        let short = windowed_key_estimates(&pitches[..5], 8, 2, KeyProfile::Temperley);
+
+        assert_eq!(short.len(), 1);
+        assert_eq!(short[0].0, 0);
+    }
+}
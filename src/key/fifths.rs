@@ -0,0 +1,74 @@
+use crate::common::Scalar;
+use crate::pitch::PitchRoot::{A, B, C, D, E, F, G};
+use crate::pitch::{Accidental, PitchClass, PitchRoot};
+use crate::vertical::Semitones;
+
+/// The natural pitch roots in the order in which they appear on the line of fifths, starting from F.
+const ROOTS_IN_FIFTHS: [PitchRoot; 7] = [F, C, G, D, A, E, B];
+
+pub trait FifthsFromC {
+    /// Returns the position on the [line of fifths](https://en.wikipedia.org/wiki/Circle_of_fifths) relative to C,
+    /// positive in the sharp direction and negative in the flat direction.
+    fn fifths_from_c(&self) -> Scalar;
+}
+
+impl FifthsFromC for PitchRoot {
+    fn fifths_from_c(&self) -> Scalar {
+        ROOTS_IN_FIFTHS
+            .iter()
+            .position(|root| root == self)
+            .expect("Every root is on the line of fifths") as Scalar
+            - 1
+    }
+}
+
+impl FifthsFromC for PitchClass {
+    fn fifths_from_c(&self) -> Scalar {
+        self.root.fifths_from_c() + 7 * self.accidental.offset.0
+    }
+}
+
+/// Returns the pitch class at a position on the line of fifths relative to C.
+///
+/// # Example
+///
+/// ```
+/// use solfege::key::{pitch_class_from_fifths, FifthsFromC};
+/// use solfege::pitch::PitchRoot::{B, C, F};
+/// use solfege::pitch::PitchRootUtils;
+///
+/// assert_eq!(pitch_class_from_fifths(0), C.natural());
+/// assert_eq!(pitch_class_from_fifths(6), F.sharp());
+/// assert_eq!(pitch_class_from_fifths(-2), B.flat());
+/// assert_eq!(pitch_class_from_fifths(B.flat().fifths_from_c()), B.flat());
+/// ```
+pub fn pitch_class_from_fifths(fifths: Scalar) -> PitchClass {
+    PitchClass {
+        root: ROOTS_IN_FIFTHS[(fifths + 1).rem_euclid(7) as usize],
+        accidental: Accidental {
+            offset: Semitones((fifths + 1).div_euclid(7)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{pitch_class_from_fifths, FifthsFromC};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::PitchRootUtils;
+
+    #[test]
+    fn fifths_from_c() {
+        assert_eq!(F.fifths_from_c(), -1);
+        assert_eq!(B.fifths_from_c(), 5);
+        assert_eq!(F.sharp().fifths_from_c(), 6);
+        assert_eq!(C.flat().fifths_from_c(), -7);
+    }
+
+    #[test]
+    fn round_trip() {
+        for fifths in -15..=15 {
+            assert_eq!(pitch_class_from_fifths(fifths).fifths_from_c(), fifths);
+        }
+    }
+}
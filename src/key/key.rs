@@ -0,0 +1,303 @@
+use crate::common::Scalar;
+use crate::interval::IntervalRoot::{Fifth, Fourth, Sixth, Third};
+use crate::interval::{major, minor, perfect};
+use crate::key::{pitch_class_from_fifths, FifthsFromC, KeySignature};
+use crate::pitch::{PitchClass, PitchClassSet};
+use crate::scale::{Scale, ScaleKind};
+use crate::vertical::TransposedBy;
+use std::fmt::{Display, Formatter};
+
+/// The mode of a key.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Mode {
+    Major,
+    Minor,
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+        })
+    }
+}
+
+impl TryFrom<&str> for Mode {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "major" | "maj" => Ok(Self::Major),
+            "minor" | "min" => Ok(Self::Minor),
+            _ => Err("Could not recognise mode"),
+        }
+    }
+}
+
+/// A [key](https://en.wikipedia.org/wiki/Key_(music)): a tonic pitch class with a mode.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::key::{Key, Mode};
+/// use solfege::pitch::PitchRoot::{A, F};
+/// use solfege::pitch::PitchRootUtils;
+///
+/// let key = Key::try_from("F♯ minor").unwrap();
+///
+/// assert_eq!(key.signature().fifths, 3);
+/// assert_eq!(key.relative(), Key { tonic: A.natural(), mode: Mode::Major });
+/// assert_eq!(key.parallel(), Key { tonic: F.sharp(), mode: Mode::Major });
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Key {
+    pub tonic: PitchClass,
+    pub mode: Mode,
+}
+
+impl Key {
+    /// Returns the key with the given signature and mode.
+    pub fn from_signature(signature: KeySignature, mode: Mode) -> Self {
+        let fifths = match mode {
+            Mode::Major => signature.fifths,
+            Mode::Minor => signature.fifths + 3,
+        };
+
+        Key {
+            tonic: pitch_class_from_fifths(fifths),
+            mode,
+        }
+    }
+
+    pub fn signature(&self) -> KeySignature {
+        let fifths = match self.mode {
+            Mode::Major => self.tonic.fifths_from_c(),
+            Mode::Minor => self.tonic.fifths_from_c() - 3,
+        };
+
+        KeySignature { fifths }
+    }
+
+    /// Returns the scale of the key, using the natural minor scale for minor keys.
+    pub fn scale(&self) -> Scale {
+        let kind = match self.mode {
+            Mode::Major => ScaleKind::Major,
+            Mode::Minor => ScaleKind::NaturalMinor,
+        };
+
+        Scale {
+            tonic: self.tonic,
+            kind,
+        }
+    }
+
+    pub fn pitch_classes(&self) -> PitchClassSet {
+        self.scale().pitch_classes()
+    }
+
+    /// Returns the key with the same signature but the other mode.
+    pub fn relative(&self) -> Self {
+        match self.mode {
+            Mode::Major => Key {
+                tonic: self.tonic.transposed_by(&major(Sixth)),
+                mode: Mode::Minor,
+            },
+            Mode::Minor => Key {
+                tonic: self.tonic.transposed_by(&minor(Third)),
+                mode: Mode::Major,
+            },
+        }
+    }
+
+    /// Returns the key with the same tonic but the other mode.
+    pub fn parallel(&self) -> Self {
+        let mode = match self.mode {
+            Mode::Major => Mode::Minor,
+            Mode::Minor => Mode::Major,
+        };
+
+        Key {
+            tonic: self.tonic,
+            mode,
+        }
+    }
+
+    /// Returns the neighbouring key one fifth up.
+    pub fn dominant(&self) -> Self {
+        Key {
+            tonic: self.tonic.transposed_by(&perfect(Fifth)),
+            mode: self.mode,
+        }
+    }
+
+    /// Returns the neighbouring key one fifth down.
+    pub fn subdominant(&self) -> Self {
+        Key {
+            tonic: self.tonic.transposed_by(&perfect(Fourth)),
+            mode: self.mode,
+        }
+    }
+
+    /// Returns the number of fifths from this key's signature to the other key's signature,
+    /// positive in the sharp direction. Enharmonic keys are 12 fifths apart.
+    pub fn fifths_to(&self, other: &Key) -> Scalar {
+        other.signature().fifths - self.signature().fifths
+    }
+
+    /// Returns the shortest distance between two keys around the circle of fifths,
+    /// treating enharmonic keys as the same key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::key::Key;
+    ///
+    /// let b_major = Key::try_from("B major").unwrap();
+    /// let d_flat_major = Key::try_from("D♭ major").unwrap();
+    ///
+    /// assert_eq!(b_major.fifths_to(&d_flat_major), -10);
+    /// assert_eq!(b_major.distance_on_circle_of_fifths(&d_flat_major), 2);
+    /// ```
+    pub fn distance_on_circle_of_fifths(&self, other: &Key) -> Scalar {
+        let fifths = self.fifths_to(other).rem_euclid(12);
+
+        fifths.min(12 - fifths)
+    }
+
+    /// Returns the same key with the tonic spelled differently, if its signature has at most 7 accidentals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::key::Key;
+    ///
+    /// let d_flat_major = Key::try_from("D♭ major").unwrap();
+    ///
+    /// assert_eq!(d_flat_major.enharmonic(), Some(Key::try_from("C♯ major").unwrap()));
+    /// assert_eq!(Key::try_from("D major").unwrap().enharmonic(), None);
+    /// ```
+    pub fn enharmonic(&self) -> Option<Self> {
+        let fifths = self.signature().fifths;
+
+        let enharmonic_fifths = if fifths > 0 { fifths - 12 } else { fifths + 12 };
+
+        (enharmonic_fifths.abs() <= 7).then(|| {
+            Key::from_signature(
+                KeySignature {
+                    fifths: enharmonic_fifths,
+                },
+                self.mode,
+            )
+        })
+    }
+
+    /// Returns whichever spelling of the key has the fewest accidentals, preferring sharps when tied.
+    pub fn simplest_spelling(&self) -> Self {
+        let fifths = self.signature().fifths.rem_euclid(12);
+
+        let fifths = if fifths > 6 { fifths - 12 } else { fifths };
+
+        Key::from_signature(KeySignature { fifths }, self.mode)
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.tonic, self.mode)
+    }
+}
+
+impl TryFrom<&str> for Key {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut words = value.split_whitespace();
+
+        let tonic = PitchClass::try_from(words.next().ok_or("String is empty")?)?;
+
+        let mode = Mode::try_from(words.next().ok_or("String has no mode")?)?;
+
+        if words.next().is_some() {
+            return Err("String is too long to be a Key");
+        }
+
+        Ok(Key { tonic, mode })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Key, Mode};
+    use crate::key::KeySignature;
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::PitchRootUtils;
+
+    fn key(value: &str) -> Key {
+        Key::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            key("Bb major"),
+            Key {
+                tonic: B.flat(),
+                mode: Mode::Major,
+            },
+        );
+        assert_eq!(
+            key("F♯ minor"),
+            Key {
+                tonic: F.sharp(),
+                mode: Mode::Minor,
+            },
+        );
+        assert!(Key::try_from("H major").is_err());
+        assert!(Key::try_from("C").is_err());
+    }
+
+    #[test]
+    fn signature() {
+        assert_eq!(key("C major").signature(), KeySignature { fifths: 0 });
+        assert_eq!(key("A minor").signature(), KeySignature { fifths: 0 });
+        assert_eq!(key("E♭ major").signature(), KeySignature { fifths: -3 });
+        assert_eq!(key("C♯ minor").signature(), KeySignature { fifths: 4 });
+        assert_eq!(
+            Key::from_signature(KeySignature { fifths: -4 }, Mode::Minor),
+            key("F minor"),
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(key("D major").dominant(), key("A major"));
+        assert_eq!(key("D major").subdominant(), key("G major"));
+        assert_eq!(key("G minor").relative(), key("B♭ major"));
+        assert_eq!(key("E♭ major").relative(), key("C minor"));
+        assert_eq!(key("E minor").parallel(), key("E major"));
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(key("C major").fifths_to(&key("E major")), 4);
+        assert_eq!(key("C major").fifths_to(&key("F minor")), -4);
+        assert_eq!(
+            key("F♯ major").distance_on_circle_of_fifths(&key("G♭ major")),
+            0
+        );
+    }
+
+    #[test]
+    fn enharmonic() {
+        assert_eq!(key("G♯ minor").enharmonic(), Some(key("A♭ minor")));
+        assert_eq!(key("G♯ major").simplest_spelling(), key("A♭ major"));
+        assert_eq!(key("G♭ major").simplest_spelling(), key("F♯ major"));
+        assert_eq!(key("C major").simplest_spelling(), key("C major"));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(key("Bb major").to_string(), "B♭ major");
+    }
+}
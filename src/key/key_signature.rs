@@ -0,0 +1,139 @@
+use crate::common::Scalar;
+use crate::key::{pitch_class_from_fifths, FifthsFromC};
+use crate::pitch::{Accidental, PitchClass, PitchRoot};
+use crate::vertical::Semitones;
+use std::fmt::{Display, Formatter};
+
+/// A [key signature](https://en.wikipedia.org/wiki/Key_signature), stored as the number of fifths away from C major:
+/// positive for sharps and negative for flats.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::key::KeySignature;
+/// use solfege::pitch::PitchRoot::{B, E, A};
+/// use solfege::pitch::PitchRootUtils;
+///
+/// let signature = KeySignature { fifths: -3 };
+///
+/// assert_eq!(signature.count(), 3);
+/// assert_eq!(signature.accidentals(), vec![B.flat(), E.flat(), A.flat()]);
+/// assert_eq!(signature.to_string(), "3♭");
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct KeySignature {
+    pub fifths: Scalar,
+}
+
+impl KeySignature {
+    /// Returns the number of sharps or flats.
+    pub fn count(&self) -> usize {
+        self.fifths.unsigned_abs() as usize
+    }
+
+    /// Returns the altered pitch classes in the order in which they are written.
+    pub fn accidentals(&self) -> Vec<PitchClass> {
+        if self.fifths >= 0 {
+            (0..self.fifths)
+                .map(|index| pitch_class_from_fifths(index + 6))
+                .collect()
+        } else {
+            (0..-self.fifths)
+                .map(|index| pitch_class_from_fifths(-index - 2))
+                .collect()
+        }
+    }
+
+    /// Returns the accidental that the signature applies to a pitch root.
+    pub fn accidental(&self, root: PitchRoot) -> Accidental {
+        let fifths = root.fifths_from_c();
+
+        let offset = if self.fifths >= 0 {
+            (self.fifths - fifths + 5).div_euclid(7)
+        } else {
+            -(fifths - self.fifths + 1).div_euclid(7)
+        };
+
+        Accidental {
+            offset: Semitones(offset),
+        }
+    }
+
+    /// Returns the pitch class that the signature gives a pitch root.
+    pub fn apply(&self, root: PitchRoot) -> PitchClass {
+        PitchClass {
+            root,
+            accidental: self.accidental(root),
+        }
+    }
+}
+
+impl Display for KeySignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.fifths {
+            0 => write!(f, "0"),
+            fifths if fifths > 0 => write!(f, "{}♯", fifths),
+            fifths => write!(f, "{}♭", -fifths),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::KeySignature;
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::PitchRootUtils;
+
+    #[test]
+    fn accidentals() {
+        assert_eq!(KeySignature { fifths: 0 }.accidentals(), vec![]);
+        assert_eq!(
+            KeySignature { fifths: 4 }.accidentals(),
+            vec![F.sharp(), C.sharp(), G.sharp(), D.sharp()],
+        );
+        assert_eq!(
+            KeySignature { fifths: -7 }.accidentals().last(),
+            Some(&F.flat()),
+        );
+    }
+
+    #[test]
+    fn apply() {
+        let signature = KeySignature { fifths: 2 };
+
+        assert_eq!(signature.apply(F), F.sharp());
+        assert_eq!(signature.apply(C), C.sharp());
+        assert_eq!(signature.apply(G), G.natural());
+
+        let signature = KeySignature { fifths: -2 };
+
+        assert_eq!(signature.apply(B), B.flat());
+        assert_eq!(signature.apply(E), E.flat());
+        assert_eq!(signature.apply(A), A.natural());
+    }
+
+    #[test]
+    fn apply_matches_accidentals() {
+        for fifths in -14..=14 {
+            let signature = KeySignature { fifths };
+
+            for class in signature.accidentals() {
+                assert_eq!(signature.apply(class.root).root, class.root);
+            }
+
+            let altered = [C, D, E, F, G, A, B]
+                .iter()
+                .map(|root| signature.apply(*root).accidental.offset.0.abs())
+                .sum::<i32>();
+
+            assert_eq!(altered as usize, signature.count());
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(KeySignature { fifths: 0 }.to_string(), "0");
+        assert_eq!(KeySignature { fifths: 2 }.to_string(), "2♯");
+        assert_eq!(KeySignature { fifths: -5 }.to_string(), "5♭");
+    }
+}
@@ -0,0 +1,10 @@
+mod detection;
+mod fifths;
+#[allow(clippy::module_inception)]
+mod key;
+mod key_signature;
+
+pub use self::detection::*;
+pub use self::fifths::*;
+pub use self::key::*;
+pub use self::key_signature::*;
@@ -1,7 +1,13 @@
 extern crate core;
 
 pub mod common;
+pub mod counterpoint;
 pub mod harmony;
 pub mod interval;
+pub mod key;
+pub mod melody;
 pub mod pitch;
+pub mod rhythm;
+pub mod scale;
+pub mod serial;
 pub mod vertical;
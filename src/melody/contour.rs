@@ -0,0 +1,51 @@
+use crate::pitch::Pitch;
+use crate::vertical::SemitonesFromC0;
+use std::fmt::{Display, Formatter};
+
+/// The direction of a melodic motion, as used in the [Parsons code](https://en.wikipedia.org/wiki/Parsons_code).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Contour {
+    Up,
+    Down,
+    Same,
+}
+
+impl Contour {
+    /// Returns the direction from one pitch to another, comparing them by their sound.
+    pub fn between(from: &Pitch, to: &Pitch) -> Self {
+        match to.semitones_from_c0().cmp(&from.semitones_from_c0()) {
+            std::cmp::Ordering::Greater => Self::Up,
+            std::cmp::Ordering::Less => Self::Down,
+            std::cmp::Ordering::Equal => Self::Same,
+        }
+    }
+}
+
+impl Display for Contour {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Up => "u",
+            Self::Down => "d",
+            Self::Same => "r",
+        })
+    }
+}
+
+/// Writes a contour as a Parsons code, starting with an asterisk for the first note.
+///
+/// # Example
+///
+/// ```
+/// use solfege::melody::{parsons_code, Contour};
+///
+/// assert_eq!(parsons_code(&[Contour::Same, Contour::Up, Contour::Down]), "*rud");
+/// ```
+pub fn parsons_code(contour: &[Contour]) -> String {
+    let mut code = String::from('*');
+
+    for direction in contour {
+        code.push_str(&direction.to_string());
+    }
+
+    code
+}
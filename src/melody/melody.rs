@@ -0,0 +1,244 @@
+use crate::harmony::Dyad;
+use crate::interval::Interval;
+use crate::melody::{parsons_code, Contour};
+use crate::pitch::{Pitch, PitchDyad};
+use crate::rhythm::{Duration, Note};
+use crate::vertical::{InSemitones, Semitones, SemitonesFromC0, StepsFromC0};
+use std::fmt::{Display, Formatter};
+
+/// The interval between two consecutive notes of a melody, with its direction.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MelodicInterval {
+    pub interval: Interval,
+    pub direction: Contour,
+}
+
+impl Display for MelodicInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.direction {
+            Contour::Up => write!(f, "+{}", self.interval),
+            Contour::Down => write!(f, "-{}", self.interval),
+            Contour::Same => write!(f, "{}", self.interval),
+        }
+    }
+}
+
+/// An ordered line of notes, which unlike a [PitchSet](crate::pitch::PitchSet) keeps repetitions and order.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::melody::Melody;
+/// use solfege::pitch::{PitchClassUtils, PitchDyad};
+/// use solfege::pitch::PitchRoot::{C, D, E, G};
+///
+/// let melody = Melody::from_pitches(&[C.o(4), E.o(4), D.o(4), D.o(4), G.o(4), C.o(4)]);
+///
+/// assert_eq!(melody.parsons_code(), "*udrud");
+/// assert_eq!(melody.ambitus(), Some(PitchDyad::from((C.o(4), G.o(4)))));
+///
+/// let intervals = melody
+///     .intervals()
+///     .iter()
+///     .map(|interval| interval.to_string())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(intervals, vec!["+M3", "-M2", "P1", "+P4", "-P5"]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Melody {
+    pub notes: Vec<Note>,
+}
+
+impl Melody {
+    /// Creates a melody of quarter notes.
+    pub fn from_pitches(pitches: &[Pitch]) -> Self {
+        Melody {
+            notes: pitches
+                .iter()
+                .map(|pitch| Note {
+                    pitch: *pitch,
+                    duration: Duration::new(1, 4),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.notes.iter().map(|note| note.pitch).collect()
+    }
+
+    /// Returns the intervals between consecutive notes.
+    pub fn intervals(&self) -> Vec<MelodicInterval> {
+        self.notes
+            .windows(2)
+            .map(|pair| MelodicInterval {
+                interval: Interval::from(&Dyad::from((pair[0].pitch, pair[1].pitch))),
+                direction: Contour::between(&pair[0].pitch, &pair[1].pitch),
+            })
+            .collect()
+    }
+
+    pub fn contour(&self) -> Vec<Contour> {
+        self.notes
+            .windows(2)
+            .map(|pair| Contour::between(&pair[0].pitch, &pair[1].pitch))
+            .collect()
+    }
+
+    pub fn parsons_code(&self) -> String {
+        parsons_code(&self.contour())
+    }
+
+    /// Returns the lowest and highest notes, which make up the [range](https://en.wikipedia.org/wiki/Range_(music)).
+    pub fn ambitus(&self) -> Option<PitchDyad> {
+        let lowest = self
+            .pitches()
+            .into_iter()
+            .min_by_key(|pitch| pitch.semitones_from_c0())?;
+        let highest = self
+            .pitches()
+            .into_iter()
+            .max_by_key(|pitch| pitch.semitones_from_c0())?;
+
+        Some(PitchDyad::from((lowest, highest)))
+    }
+
+    /// Returns the widest interval between consecutive notes.
+    pub fn largest_leap(&self) -> Option<MelodicInterval> {
+        self.intervals()
+            .into_iter()
+            .max_by_key(|interval| interval.interval.in_semitones())
+    }
+
+    /// Returns the number of steps, moving by a second, per leap, moving by a third or more.
+    /// Repeated notes count as neither. Returns `None` if there are no leaps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::melody::Melody;
+    /// use solfege::pitch::PitchClassUtils;
+    /// use solfege::pitch::PitchRoot::{C, D, E, G};
+    ///
+    /// let melody = Melody::from_pitches(&[C.o(4), D.o(4), E.o(4), E.o(4), C.o(4), D.o(4), G.o(4)]);
+    ///
+    /// assert_eq!(melody.step_to_leap_ratio(), Some(1.5));
+    /// ```
+    pub fn step_to_leap_ratio(&self) -> Option<f64> {
+        let motions = self
+            .notes
+            .windows(2)
+            .map(|pair| {
+                (pair[1].pitch.steps_from_c0() - pair[0].pitch.steps_from_c0())
+                    .0
+                    .abs()
+            })
+            .collect::<Vec<_>>();
+
+        let steps = motions.iter().filter(|steps| **steps == 1).count();
+        let leaps = motions.iter().filter(|steps| **steps >= 2).count();
+
+        match leaps {
+            0 => None,
+            _ => Some(steps as f64 / leaps as f64),
+        }
+    }
+
+    /// Returns the [tessitura](https://en.wikipedia.org/wiki/Tessitura) as the mean pitch in semitones from C0,
+    /// weighted by how long each note sounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::melody::Melody;
+    /// use solfege::pitch::PitchClassUtils;
+    /// use solfege::pitch::PitchRoot::{C, G};
+    /// use solfege::rhythm::{Duration, Note};
+    ///
+    /// let melody = Melody {
+    ///     notes: vec![
+    ///         Note { pitch: C.o(4), duration: Duration::new(3, 4) },
+    ///         Note { pitch: G.o(4), duration: Duration::new(1, 4) },
+    ///     ],
+    /// };
+    ///
+    /// // C4 is 48 semitones above C0, G4 is 55
+    /// assert_eq!(melody.tessitura(), Some(49.75));
+    /// ```
+    pub fn tessitura(&self) -> Option<f64> {
+        let total = self
+            .notes
+            .iter()
+            .map(|note| note.duration)
+            .sum::<Duration>();
+
+        if total <= Duration::ZERO {
+            return None;
+        }
+
+        let weighted = self
+            .notes
+            .iter()
+            .map(|note| {
+                let Semitones(semitones) = note.pitch.semitones_from_c0();
+
+                semitones as f64 * note.duration.numerator() as f64
+                    / note.duration.denominator() as f64
+            })
+            .sum::<f64>();
+
+        Some(weighted * total.denominator() as f64 / total.numerator() as f64)
+    }
+}
+
+impl From<Vec<Note>> for Melody {
+    fn from(notes: Vec<Note>) -> Self {
+        Melody { notes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::IntervalRoot::Sixth;
+    use crate::interval::{minor, IntervalClassUtils};
+    use crate::melody::{Contour, Melody};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassUtils, PitchRootUtils};
+
+    #[test]
+    fn keeps_repetitions_and_order() {
+        let melody = Melody::from_pitches(&[E.o(4), C.o(4), E.o(4)]);
+
+        assert_eq!(melody.pitches(), vec![E.o(4), C.o(4), E.o(4)]);
+        assert_eq!(melody.contour(), vec![Contour::Down, Contour::Up]);
+    }
+
+    #[test]
+    fn largest_leap() {
+        let melody = Melody::from_pitches(&[C.o(4), A.flat().o(4), G.o(4), E.o(4)]);
+
+        let leap = melody.largest_leap().unwrap();
+
+        assert_eq!(leap.interval, minor(Sixth).simple());
+        assert_eq!(leap.direction, Contour::Up);
+    }
+
+    #[test]
+    fn empty_melody() {
+        let melody = Melody::default();
+
+        assert_eq!(melody.ambitus(), None);
+        assert_eq!(melody.largest_leap(), None);
+        assert_eq!(melody.tessitura(), None);
+        assert_eq!(melody.parsons_code(), "*");
+    }
+
+    #[test]
+    fn no_leaps() {
+        assert_eq!(
+            Melody::from_pitches(&[C.o(4), D.o(4)]).step_to_leap_ratio(),
+            None
+        );
+    }
+}
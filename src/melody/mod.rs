@@ -0,0 +1,7 @@
+mod contour;
+#[allow(clippy::module_inception)]
+mod melody;
+mod transformations;
+
+pub use self::contour::*;
+pub use self::melody::*;
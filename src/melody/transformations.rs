@@ -0,0 +1,366 @@
+use crate::harmony::Dyad;
+use crate::interval::IntervalRoot::Unison;
+use crate::interval::{perfect, Interval};
+use crate::key::Key;
+use crate::melody::{Contour, MelodicInterval, Melody};
+use crate::pitch::Pitch;
+use crate::rhythm::{Duration, Note};
+use crate::scale::TransposedWithin;
+use crate::vertical::{
+    InSteps, Octaves, Semitones, SemitonesFromC0, Steps, StepsFromC0, TransposedBy,
+};
+
+/// Motivic transformations, each returning a new melody.
+impl Melody {
+    /// Returns the notes in reverse order.
+    pub fn retrograde(&self) -> Melody {
+        Melody {
+            notes: self.notes.iter().rev().copied().collect(),
+        }
+    }
+
+    /// Mirrors every pitch around an axis, keeping the exact intervals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::melody::Melody;
+    /// use solfege::pitch::{PitchClassUtils, PitchRootUtils};
+    /// use solfege::pitch::PitchRoot::{A, C, E, F, G};
+    ///
+    /// let melody = Melody::from_pitches(&[C.o(4), E.o(4), F.sharp().o(4)]);
+    ///
+    /// assert_eq!(
+    ///     melody.inverted_around(&C.o(4)).pitches(),
+    ///     vec![C.o(4), A.flat().o(3), G.flat().o(3)],
+    /// );
+    /// ```
+    pub fn inverted_around(&self, axis: &Pitch) -> Melody {
+        self.map_pitches(|pitch| {
+            let dyad = Dyad::from((*axis, *pitch));
+            let interval = Interval::from(&dyad);
+
+            if dyad.high == *axis {
+                axis.transposed_by(&interval)
+            } else {
+                transposed_down_by(axis, &interval)
+            }
+        })
+    }
+
+    /// Mirrors every pitch around an axis by scale degrees, so that the intervals follow the key.
+    ///
+    /// Chromatically altered pitches are altered the other way, so a raised degree becomes a lowered one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::key::Key;
+    /// use solfege::melody::Melody;
+    /// use solfege::pitch::PitchClassUtils;
+    /// use solfege::pitch::PitchRoot::{A, C, E, F, G};
+    ///
+    /// let melody = Melody::from_pitches(&[C.o(4), E.o(4), G.o(4)]);
+    /// let key = Key::try_from("C major").unwrap();
+    ///
+    /// assert_eq!(melody.inverted_within(&key, &C.o(4)).pitches(), vec![C.o(4), A.o(3), F.o(3)]);
+    /// ```
+    pub fn inverted_within(&self, key: &Key, axis: &Pitch) -> Melody {
+        let scale = key.scale();
+        let degrees = scale.degrees();
+
+        // How far a pitch is raised or lowered from the scale degree on its root
+        let alteration = |pitch: &Pitch| {
+            degrees
+                .iter()
+                .find(|degree| degree.root == pitch.class.root)
+                .map_or(Semitones(0), |degree| {
+                    pitch.class.accidental.offset - degree.accidental.offset
+                })
+        };
+
+        self.map_pitches(|pitch| {
+            let steps = pitch.steps_from_c0() - axis.steps_from_c0();
+            let mirrored = pitch.transposed_within(&scale, Steps(-2 * steps.0));
+
+            // A raised degree mirrors to a lowered one and vice versa
+            Pitch::from_c0(
+                mirrored.steps_from_c0(),
+                mirrored.semitones_from_c0() - alteration(&mirrored) - alteration(pitch),
+            )
+        })
+    }
+
+    /// Multiplies every duration by a factor, which can be a fraction such as 3/2.
+    pub fn augmented(&self, factor: Duration) -> Melody {
+        self.map_durations(|note| note.duration * factor)
+    }
+
+    /// Divides every duration by a factor, which can be a fraction such as 3/2.
+    ///
+    /// Panics if the factor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::melody::Melody;
+    /// use solfege::pitch::PitchClassUtils;
+    /// use solfege::pitch::PitchRoot::C;
+    /// use solfege::rhythm::Duration;
+    ///
+    /// let melody = Melody::from_pitches(&[C.o(4)]);
+    ///
+    /// assert_eq!(melody.diminished(Duration::new(2, 1)).notes[0].duration, Duration::new(1, 8));
+    /// assert_eq!(melody.augmented(Duration::new(3, 2)).notes[0].duration, Duration::new(3, 8));
+    /// ```
+    pub fn diminished(&self, factor: Duration) -> Melody {
+        self.map_durations(|note| note.duration / factor)
+    }
+
+    /// Returns the melody followed by copies that are each transposed further by an exact interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::interval::{major, IntervalClassUtils};
+    /// use solfege::interval::IntervalRoot::Second;
+    /// use solfege::melody::{Contour, MelodicInterval, Melody};
+    /// use solfege::pitch::{PitchClassUtils, PitchRootUtils};
+    /// use solfege::pitch::PitchRoot::{A, B, C, D, E};
+    ///
+    /// let melody = Melody::from_pitches(&[C.o(4), E.o(4)]);
+    /// let step_down = MelodicInterval { interval: major(Second).simple(), direction: Contour::Down };
+    ///
+    /// assert_eq!(
+    ///     melody.real_sequence(&step_down, 2).pitches(),
+    ///     vec![C.o(4), E.o(4), B.flat().o(3), D.o(4), A.flat().o(3), C.o(4)],
+    /// );
+    /// ```
+    pub fn real_sequence(&self, interval: &MelodicInterval, repetitions: usize) -> Melody {
+        self.sequence(repetitions, |melody| {
+            melody.map_pitches(|pitch| match interval.direction {
+                Contour::Up => pitch.transposed_by(&interval.interval),
+                Contour::Down => transposed_down_by(pitch, &interval.interval),
+                Contour::Same => *pitch,
+            })
+        })
+    }
+
+    /// Returns the melody followed by copies that are each moved further by the number of scale degrees of an interval,
+    /// so that its quality adapts to the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::interval::{major, IntervalClassUtils};
+    /// use solfege::interval::IntervalRoot::Second;
+    /// use solfege::key::Key;
+    /// use solfege::melody::{Contour, MelodicInterval, Melody};
+    /// use solfege::pitch::PitchClassUtils;
+    /// use solfege::pitch::PitchRoot::{C, D, E, F, G};
+    ///
+    /// let melody = Melody::from_pitches(&[C.o(4), E.o(4)]);
+    /// let step_up = MelodicInterval { interval: major(Second).simple(), direction: Contour::Up };
+    /// let key = Key::try_from("C major").unwrap();
+    ///
+    /// assert_eq!(
+    ///     melody.tonal_sequence(&step_up, &key, 2).pitches(),
+    ///     vec![C.o(4), E.o(4), D.o(4), F.o(4), E.o(4), G.o(4)],
+    /// );
+    /// ```
+    pub fn tonal_sequence(
+        &self,
+        interval: &MelodicInterval,
+        key: &Key,
+        repetitions: usize,
+    ) -> Melody {
+        let scale = key.scale();
+
+        let degrees = match interval.direction {
+            Contour::Up => interval.interval.in_steps(),
+            Contour::Down => Steps(-interval.interval.in_steps().0),
+            Contour::Same => Steps(0),
+        };
+
+        self.sequence(repetitions, |melody| {
+            melody.map_pitches(|pitch| pitch.transposed_within(&scale, degrees))
+        })
+    }
+
+    fn sequence(&self, repetitions: usize, next: impl Fn(&Melody) -> Melody) -> Melody {
+        let mut current = self.clone();
+        let mut notes = self.notes.clone();
+
+        for _ in 0..repetitions {
+            current = next(&current);
+            notes.extend(current.notes.iter().copied());
+        }
+
+        Melody { notes }
+    }
+
+    fn map_pitches(&self, map: impl Fn(&Pitch) -> Pitch) -> Melody {
+        Melody {
+            notes: self
+                .notes
+                .iter()
+                .map(|note| Note {
+                    pitch: map(&note.pitch),
+                    duration: note.duration,
+                })
+                .collect(),
+        }
+    }
+
+    fn map_durations(&self, map: impl Fn(&Note) -> Duration) -> Melody {
+        Melody {
+            notes: self
+                .notes
+                .iter()
+                .map(|note| Note {
+                    pitch: note.pitch,
+                    duration: map(note),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Transposes a pitch down by an interval, by dropping a whole number of octaves below it and rising by the rest.
+fn transposed_down_by(pitch: &Pitch, interval: &Interval) -> Pitch {
+    let octaves = interval.octaves + Octaves(1);
+
+    let lowered = pitch.transposed_by(&Interval {
+        octaves: Octaves(-octaves.0),
+        class: perfect(Unison),
+    });
+
+    lowered.transposed_by(
+        &(Interval {
+            octaves,
+            class: perfect(Unison),
+        } - *interval),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::IntervalRoot::{Second, Unison};
+    use crate::interval::{major, perfect, IntervalClassUtils};
+    use crate::key::Key;
+    use crate::melody::{Contour, MelodicInterval, Melody};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassUtils, PitchRootUtils};
+    use crate::rhythm::{Duration, Note};
+
+    #[test]
+    fn retrograde() {
+        let melody = Melody {
+            notes: vec![
+                Note {
+                    pitch: C.o(4),
+                    duration: Duration::new(1, 2),
+                },
+                Note {
+                    pitch: D.o(4),
+                    duration: Duration::new(1, 4),
+                },
+            ],
+        };
+
+        let retrograde = melody.retrograde();
+
+        assert_eq!(retrograde.notes[0].pitch, D.o(4));
+        assert_eq!(retrograde.notes[1].duration, Duration::new(1, 2));
+        assert_eq!(retrograde.retrograde(), melody);
+    }
+
+    #[test]
+    fn chromatic_inversion_is_spelled() {
+        let melody = Melody::from_pitches(&[E.flat().o(4), G.o(4), B.flat().o(4)]);
+
+        assert_eq!(
+            melody.inverted_around(&D.o(4)).pitches(),
+            vec![C.sharp().o(4), A.o(3), F.sharp().o(3)]
+        );
+    }
+
+    #[test]
+    fn tonal_inversion_in_minor() {
+        let melody = Melody::from_pitches(&[A.o(4), C.o(5), E.o(5)]);
+        let key = Key::try_from("A minor").unwrap();
+
+        assert_eq!(
+            melody.inverted_within(&key, &A.o(4)).pitches(),
+            vec![A.o(4), F.o(4), D.o(4)]
+        );
+    }
+
+    #[test]
+    fn tonal_inversion_mirrors_alterations() {
+        let melody = Melody::from_pitches(&[F.sharp().o(4), E.flat().o(4), B.flat().o(4)]);
+        let key = Key::try_from("C major").unwrap();
+
+        assert_eq!(
+            melody.inverted_within(&key, &C.o(4)).pitches(),
+            vec![G.flat().o(3), A.sharp().o(3), D.sharp().o(3)]
+        );
+
+        let melody = Melody::from_pitches(&[G.sharp().o(4), C.sharp().o(5)]);
+        let key = Key::try_from("A minor").unwrap();
+
+        assert_eq!(
+            melody.inverted_within(&key, &A.o(4)).pitches(),
+            vec![B.flat().o(4), F.flat().o(4)]
+        );
+    }
+
+    #[test]
+    fn inversion_across_octaves() {
+        let melody = Melody::from_pitches(&[C.o(4), D.o(5), B.flat().o(2)]);
+
+        assert_eq!(
+            melody.inverted_around(&C.o(4)).pitches(),
+            vec![C.o(4), B.flat().o(2), D.o(5)]
+        );
+    }
+
+    #[test]
+    fn real_sequence_down_by_compound_intervals() {
+        let melody = Melody::from_pitches(&[C.o(4), E.o(4)]);
+
+        let ninth_down = MelodicInterval {
+            interval: major(Second).compound(1),
+            direction: Contour::Down,
+        };
+        let octave_down = MelodicInterval {
+            interval: perfect(Unison).compound(1),
+            direction: Contour::Down,
+        };
+
+        assert_eq!(
+            melody.real_sequence(&ninth_down, 1).pitches(),
+            vec![C.o(4), E.o(4), B.flat().o(2), D.o(3)]
+        );
+        assert_eq!(
+            melody.real_sequence(&octave_down, 1).pitches(),
+            vec![C.o(4), E.o(4), C.o(3), E.o(3)]
+        );
+    }
+
+    #[test]
+    fn augmentation_by_fractions() {
+        let melody = Melody::from_pitches(&[C.o(4), D.o(4)]);
+
+        assert_eq!(
+            melody
+                .augmented(Duration::new(3, 2))
+                .diminished(Duration::new(3, 2)),
+            melody
+        );
+        assert_eq!(
+            melody.diminished(Duration::new(2, 3)).notes[1].duration,
+            Duration::new(3, 8)
+        );
+    }
+}
@@ -50,10 +50,10 @@ impl TryFrom<&str> for Accidental {
         for char in value.chars() {
             offset += match char {
                 '𝄫' => -2,
-                '♭' => -1,
+                '♭' | 'b' => -1,
                 '♮' => 0,
-                '♯' => 1,
-                '𝄪' => 2,
+                '♯' | '#' => 1,
+                '𝄪' | 'x' => 2,
                 _ => return Err("Could not parse Accidental"),
             }
         }
@@ -66,9 +66,21 @@ impl TryFrom<&str> for Accidental {
 
 #[cfg(test)]
 mod test {
-    use crate::pitch::Accidental;
+    use crate::pitch::{Accidental, FLAT, NATURAL, SHARP};
     use crate::vertical::{Semitones, TransposedBy};
 
+    #[test]
+    fn from_str() {
+        assert_eq!(Accidental::try_from("♯"), Ok(SHARP));
+        assert_eq!(Accidental::try_from("b"), Ok(FLAT));
+        assert_eq!(Accidental::try_from(""), Ok(NATURAL));
+        assert_eq!(
+            Accidental::try_from("#x"),
+            Ok(Accidental { offset: 3.into() }),
+        );
+        assert!(Accidental::try_from("?").is_err());
+    }
+
     #[test]
     fn transposition() {
         assert_eq!(
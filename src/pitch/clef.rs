@@ -1,26 +1,134 @@
 use crate::common::Scalar;
-use crate::pitch::PitchRoot::{B, C, D};
-use crate::pitch::{distance_of_pitch_set_to_pitch, Pitch, PitchClassUtils, PitchSet};
-use crate::vertical::Semitones;
-use serde::Serialize;
+use crate::pitch::PitchRoot::{B, C, F, G};
+use crate::pitch::{
+    distance_of_pitch_set_to_pitch, Pitch, PitchClassUtils, PitchRootUtils, PitchSet, MIDDLE_LINE,
+};
+use crate::vertical::{Octaves, Semitones, TransposedBy};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Display, Formatter};
 
+/// The symbol of a clef, which fixes a pitch to the line it is drawn on.
+#[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
+pub enum ClefSign {
+    /// Fixes G4.
+    G,
+    /// Fixes C4.
+    C,
+    /// Fixes F3.
+    F,
+    /// Has no pitch; the staff is laid out like a treble staff.
+    Percussion,
+}
+
+impl Display for ClefSign {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::G => "G",
+            Self::C => "C",
+            Self::F => "F",
+            Self::Percussion => "percussion",
+        })
+    }
+}
+
 /// A [clef](https://en.wikipedia.org/wiki/Clef) is shown at the beginning of a stave to indicate which pitches are represented by the lines.
-#[derive(Clone, Debug, Copy, Serialize, Eq, PartialEq, Hash)]
-pub enum Clef {
-    Treble,
-    Alto,
-    Bass,
+///
+/// It is made of a sign, the line it is drawn on (counting from 1 at the bottom of a five-line staff),
+/// and the number of octaves the music sounds above or below the written pitch.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::pitch::Clef;
+/// use solfege::pitch::PitchClassUtils;
+/// use solfege::pitch::PitchRoot::{A, B, F};
+///
+/// assert_eq!(Clef::TENOR.center(), A.o(3));
+/// assert_eq!(Clef::TREBLE_8VB.center(), B.o(3));
+/// assert_eq!(Clef::try_from("bass 8vb"), Ok(Clef::BASS_8VB));
+/// assert_eq!(Clef::try_from("C5").unwrap().center(), F.o(3));
+/// ```
+#[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
+pub struct Clef {
+    pub sign: ClefSign,
+    pub line: Scalar,
+    pub octave_shift: Scalar,
 }
 
 pub type ClefSet = Vec<Clef>;
 
 impl Clef {
+    pub const TREBLE: Clef = Clef::new(ClefSign::G, 2, 0);
+    pub const TREBLE_8VB: Clef = Clef::new(ClefSign::G, 2, -1);
+    pub const TREBLE_8VA: Clef = Clef::new(ClefSign::G, 2, 1);
+    pub const FRENCH_VIOLIN: Clef = Clef::new(ClefSign::G, 1, 0);
+    pub const SOPRANO: Clef = Clef::new(ClefSign::C, 1, 0);
+    pub const MEZZO_SOPRANO: Clef = Clef::new(ClefSign::C, 2, 0);
+    pub const ALTO: Clef = Clef::new(ClefSign::C, 3, 0);
+    pub const TENOR: Clef = Clef::new(ClefSign::C, 4, 0);
+    pub const BARITONE: Clef = Clef::new(ClefSign::F, 3, 0);
+    pub const BASS: Clef = Clef::new(ClefSign::F, 4, 0);
+    pub const BASS_8VB: Clef = Clef::new(ClefSign::F, 4, -1);
+    pub const SUBBASS: Clef = Clef::new(ClefSign::F, 5, 0);
+    pub const PERCUSSION: Clef = Clef::new(ClefSign::Percussion, 3, 0);
+
+    /// Panics if the line is not one of the five lines of the staff,
+    /// or if a percussion clef is not drawn on the middle line.
+    pub const fn new(sign: ClefSign, line: Scalar, octave_shift: Scalar) -> Self {
+        assert!(
+            line >= 1 && line <= 5,
+            "A clef must be drawn on one of the five lines"
+        );
+        assert!(
+            !matches!(sign, ClefSign::Percussion) || line == 3,
+            "A percussion clef must be drawn on the middle line"
+        );
+
+        Clef {
+            sign,
+            line,
+            octave_shift,
+        }
+    }
+
+    /// Returns the pitch that the clef fixes to its line.
+    pub fn reference(&self) -> Pitch {
+        let pitch = match self.sign {
+            ClefSign::G => G.o(4),
+            ClefSign::C => C.o(4),
+            ClefSign::F => F.o(3),
+            ClefSign::Percussion => B.o(4),
+        };
+
+        Pitch {
+            octave: pitch.octave.transposed_by(Octaves(self.octave_shift)),
+            class: pitch.class,
+        }
+    }
+
+    /// Returns the pitch on the middle line of the staff.
     pub fn center(&self) -> Pitch {
-        match *self {
-            Self::Treble => B.o(4),
-            Self::Alto => C.o(4),
-            Self::Bass => D.o(3),
+        let (root, octave) = self.pitch_at(MIDDLE_LINE);
+
+        Pitch {
+            octave,
+            class: root.natural(),
+        }
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        match (self.sign, self.line) {
+            (ClefSign::G, 1) => Some("french violin"),
+            (ClefSign::G, 2) => Some("treble"),
+            (ClefSign::C, 1) => Some("soprano"),
+            (ClefSign::C, 2) => Some("mezzo-soprano"),
+            (ClefSign::C, 3) => Some("alto"),
+            (ClefSign::C, 4) => Some("tenor"),
+            (ClefSign::F, 3) => Some("baritone"),
+            (ClefSign::F, 4) => Some("bass"),
+            (ClefSign::F, 5) => Some("subbass"),
+            (ClefSign::Percussion, 3) => Some("percussion"),
+            _ => None,
         }
     }
 }
@@ -43,29 +151,96 @@ pub fn best_clef(clefs: &ClefSet, pitches: &PitchSet) -> Clef {
         }
     }
 
-    return best_clef.unwrap();
+    best_clef.unwrap()
 }
 
 impl Display for Clef {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match self {
-            Clef::Treble => "treble",
-            Clef::Alto => "alto",
-            Clef::Bass => "bass",
-        })
+        match self.name() {
+            Some(name) => f.write_str(name)?,
+            None => write!(f, "{}{}", self.sign, self.line)?,
+        }
+
+        match self.octave_shift {
+            0 => Ok(()),
+            1 => f.write_str(" 8va"),
+            -1 => f.write_str(" 8vb"),
+            2 => f.write_str(" 15ma"),
+            -2 => f.write_str(" 15mb"),
+            shift => write!(f, " {:+} octaves", shift),
+        }
     }
 }
 
 impl TryFrom<&str> for Clef {
-    type Error = ();
+    type Error = &'static str;
 
+    /// Parses a clef by name, e.g. "tenor", or by sign and line, e.g. "C4", optionally followed by an octave shift.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "treble" => Ok(Clef::Treble),
-            "bass" => Ok(Clef::Bass),
-            "alto" => Ok(Clef::Alto),
-            _ => Err(()),
+        let value = value.trim().to_lowercase();
+
+        let (base, octave_shift) = match value.rsplit_once(' ') {
+            Some((base, "8va")) => (base, 1),
+            Some((base, "8vb")) => (base, -1),
+            Some((base, "15ma")) => (base, 2),
+            Some((base, "15mb")) => (base, -2),
+            _ => (value.as_str(), 0),
+        };
+
+        let named = [
+            Clef::FRENCH_VIOLIN,
+            Clef::TREBLE,
+            Clef::SOPRANO,
+            Clef::MEZZO_SOPRANO,
+            Clef::ALTO,
+            Clef::TENOR,
+            Clef::BARITONE,
+            Clef::BASS,
+            Clef::SUBBASS,
+            Clef::PERCUSSION,
+        ];
+
+        if let Some(clef) = named.iter().find(|clef| clef.name() == Some(base)) {
+            return Ok(Clef::new(clef.sign, clef.line, octave_shift));
         }
+
+        let sign = match base.get(0..1) {
+            Some("g") => ClefSign::G,
+            Some("c") => ClefSign::C,
+            Some("f") => ClefSign::F,
+            _ => return Err("Could not recognise clef"),
+        };
+
+        let line = match base[1..].parse::<Scalar>() {
+            Ok(line @ 1..=5) => line,
+            _ => return Err("Could not recognise clef line"),
+        };
+
+        Ok(Clef::new(sign, line, octave_shift))
+    }
+}
+
+/// Serializes a clef as its display string, e.g. "treble" or "bass 8vb".
+///
+/// Clefs used to be serialized as the enum variants "Treble", "Alto" and "Bass";
+/// they are now lowercase strings like every other clef, but the old names still deserialize.
+impl Serialize for Clef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Clef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        Clef::try_from(value.as_str()).map_err(de::Error::custom)
     }
 }
 
@@ -78,16 +253,94 @@ mod test {
     use super::best_clef;
     use super::Clef;
     use super::ClefSet;
+    use super::ClefSign;
 
     #[test]
     fn test_best_clef() {
-        let clefs = ClefSet::from([Clef::Treble, Clef::Bass]);
+        let clefs = ClefSet::from([Clef::TREBLE, Clef::BASS]);
 
         let pitches_treble = PitchSet::from([B.o(3), E.o(4), G.o(4)]);
         let pitches_bass = PitchSet::from([E.o(3), G.o(3), C.o(4)]);
 
-        assert_eq!(best_clef(&clefs, &pitches_treble), Clef::Treble,);
+        assert_eq!(best_clef(&clefs, &pitches_treble), Clef::TREBLE,);
 
-        assert_eq!(best_clef(&clefs, &pitches_bass), Clef::Bass,);
+        assert_eq!(best_clef(&clefs, &pitches_bass), Clef::BASS,);
+    }
+
+    #[test]
+    fn test_best_clef_with_tenor() {
+        let clefs = ClefSet::from([Clef::TREBLE, Clef::TENOR, Clef::BASS]);
+
+        let pitches = PitchSet::from([F.o(3), A.o(3), C.o(4)]);
+
+        assert_eq!(best_clef(&clefs, &pitches), Clef::TENOR);
+    }
+
+    #[test]
+    fn test_centers() {
+        assert_eq!(Clef::TREBLE.center(), B.o(4));
+        assert_eq!(Clef::FRENCH_VIOLIN.center(), D.o(5));
+        assert_eq!(Clef::TREBLE_8VA.center(), B.o(5));
+        assert_eq!(Clef::SOPRANO.center(), G.o(4));
+        assert_eq!(Clef::MEZZO_SOPRANO.center(), E.o(4));
+        assert_eq!(Clef::ALTO.center(), C.o(4));
+        assert_eq!(Clef::BARITONE.center(), F.o(3));
+        assert_eq!(Clef::BASS.center(), D.o(3));
+        assert_eq!(Clef::BASS_8VB.center(), D.o(2));
+        assert_eq!(Clef::SUBBASS.center(), B.o(2));
+    }
+
+    #[test]
+    fn test_display_and_parsing() {
+        let clefs = [
+            Clef::TREBLE,
+            Clef::TREBLE_8VB,
+            Clef::TREBLE_8VA,
+            Clef::FRENCH_VIOLIN,
+            Clef::SOPRANO,
+            Clef::MEZZO_SOPRANO,
+            Clef::ALTO,
+            Clef::TENOR,
+            Clef::BARITONE,
+            Clef::BASS,
+            Clef::BASS_8VB,
+            Clef::SUBBASS,
+            Clef::PERCUSSION,
+            Clef::try_from("C5").unwrap(),
+        ];
+
+        for clef in clefs {
+            assert_eq!(Clef::try_from(clef.to_string().as_str()), Ok(clef));
+        }
+
+        assert_eq!(Clef::TREBLE_8VB.to_string(), "treble 8vb");
+        assert_eq!(Clef::try_from("Treble"), Ok(Clef::TREBLE));
+        assert_eq!(Clef::try_from("G2"), Ok(Clef::TREBLE));
+        assert!(Clef::try_from("C6").is_err());
+        assert!(Clef::try_from("viola").is_err());
+        assert!(Clef::try_from("percussion2").is_err());
+    }
+
+    #[test]
+    fn test_serialization() {
+        for clef in [Clef::TREBLE, Clef::ALTO, Clef::BASS_8VB, Clef::PERCUSSION] {
+            let json = serde_json::to_string(&clef).unwrap();
+
+            assert_eq!(serde_json::from_str::<Clef>(&json).unwrap(), clef);
+        }
+
+        assert_eq!(serde_json::to_string(&Clef::TREBLE).unwrap(), "\"treble\"");
+        assert_eq!(serde_json::to_string(&Clef::TENOR).unwrap(), "\"tenor\"");
+        assert_eq!(
+            serde_json::from_str::<Clef>("\"Bass\"").unwrap(),
+            Clef::BASS
+        );
+        assert!(serde_json::from_str::<Clef>("\"viola\"").is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_percussion_off_the_middle_line() {
+        Clef::new(ClefSign::Percussion, 2, 0);
     }
 }
@@ -0,0 +1,246 @@
+use crate::common::Scalar;
+use crate::pitch::{Clef, ClefSet, Pitch};
+use std::fmt::{Display, Formatter};
+
+/// Restrictions and weights for planning the clefs of a single line.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ClefPlanOptions {
+    /// The clefs the instrument reads, the first of which is preferred when they are equally good.
+    pub clefs: ClefSet,
+    /// The cost of every ledger line.
+    pub ledger_line_penalty: Scalar,
+    /// The cost of every clef change, which keeps the clef from changing back and forth every few notes.
+    pub switch_penalty: Scalar,
+    /// Whether 8va and 8vb lines may be used instead of changing clef.
+    pub ottavas: bool,
+    /// The cost of starting an ottava line.
+    pub ottava_penalty: Scalar,
+}
+
+impl ClefPlanOptions {
+    /// Creates options for the given clefs, without ottava lines.
+    pub fn new(clefs: ClefSet) -> Self {
+        ClefPlanOptions {
+            clefs,
+            ledger_line_penalty: 10,
+            switch_penalty: 40,
+            ottavas: false,
+            ottava_penalty: 30,
+        }
+    }
+}
+
+/// How a single note is notated; its clef, and the octaves of the ottava line above (8va) or below (8vb) it.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StaffNotation {
+    pub clef: Clef,
+    pub ottava: Scalar,
+}
+
+impl StaffNotation {
+    /// Returns the clef that the note is effectively read in.
+    pub fn reading_clef(&self) -> Clef {
+        Clef {
+            octave_shift: self.clef.octave_shift + self.ottava,
+            ..self.clef
+        }
+    }
+}
+
+impl Display for StaffNotation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.ottava {
+            0 => write!(f, "{}", self.clef),
+            1 => write!(f, "{}, 8va", self.clef),
+            -1 => write!(f, "{}, 8vb", self.clef),
+            ottava => write!(f, "{}, {:+} octaves", self.clef, ottava),
+        }
+    }
+}
+
+/// The notation of every note of a line.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ClefPlan {
+    pub notations: Vec<StaffNotation>,
+}
+
+impl ClefPlan {
+    /// Returns the index of every note at which the clef or ottava changes, with its new notation,
+    /// starting with the first note.
+    pub fn changes(&self) -> Vec<(usize, StaffNotation)> {
+        self.notations
+            .iter()
+            .enumerate()
+            .filter(|(index, notation)| *index == 0 || self.notations[index - 1] != **notation)
+            .map(|(index, notation)| (index, *notation))
+            .collect()
+    }
+
+    /// Returns the total number of ledger lines in the plan.
+    pub fn ledger_lines(&self, pitches: &[Pitch]) -> Scalar {
+        pitches
+            .iter()
+            .zip(&self.notations)
+            .map(|(pitch, notation)| notation.reading_clef().position_of(pitch).ledger_lines())
+            .sum()
+    }
+}
+
+/// Plans where a line changes clef, minimising ledger lines while penalising every change,
+/// unlike [best_clef](crate::pitch::best_clef) which looks at a single set at a time.
+///
+/// # Example
+///
+/// ```
+/// use solfege::pitch::{plan_clefs, Clef, ClefPlanOptions, ClefSet, PitchClassUtils};
+/// use solfege::pitch::PitchRoot::{A, B, C, D, E, F, G};
+///
+/// // A cello line that climbs into the tenor range and comes back down
+/// let pitches = [
+///     C.o(2), G.o(2), C.o(3), G.o(3), C.o(4), E.o(4), G.o(4), A.o(4),
+///     F.o(4), D.o(4), B.o(3), G.o(3), C.o(3), G.o(2), C.o(2),
+/// ];
+///
+/// let options = ClefPlanOptions::new(ClefSet::from([Clef::BASS, Clef::TENOR]));
+///
+/// let plan = plan_clefs(&pitches, &options);
+/// let changes = plan.changes();
+///
+/// assert_eq!(changes.len(), 3);
+/// assert_eq!(changes[1].1.clef, Clef::TENOR);
+/// assert_eq!(changes[2].1.clef, Clef::BASS);
+/// ```
+pub fn plan_clefs(pitches: &[Pitch], options: &ClefPlanOptions) -> ClefPlan {
+    assert!(!options.clefs.is_empty(), "No clefs provided");
+
+    let ottavas: &[Scalar] = if options.ottavas { &[0, 1, -1] } else { &[0] };
+
+    let states = options
+        .clefs
+        .iter()
+        .flat_map(|&clef| {
+            ottavas
+                .iter()
+                .map(move |&ottava| StaffNotation { clef, ottava })
+        })
+        .collect::<Vec<_>>();
+
+    let note_cost = |pitch: &Pitch, state: &StaffNotation| {
+        let ledger_lines = state.reading_clef().position_of(pitch).ledger_lines();
+
+        // Ottava lines that are not needed cost a little, so that they end as soon as they can
+        ledger_lines * options.ledger_line_penalty + state.ottava.abs()
+    };
+
+    let transition_cost = |from: Option<&StaffNotation>, to: &StaffNotation| {
+        let switch = match from {
+            Some(from) if from.clef != to.clef => options.switch_penalty,
+            _ => 0,
+        };
+
+        let ottava = match from {
+            Some(from) if from.ottava == to.ottava => 0,
+            _ if to.ottava == 0 => 0,
+            _ => options.ottava_penalty,
+        };
+
+        switch + ottava
+    };
+
+    // The lowest cost of ending each note in every state, with the state of the previous note it was reached from
+    let mut costs: Vec<Vec<(Scalar, usize)>> = Vec::with_capacity(pitches.len());
+
+    for (index, pitch) in pitches.iter().enumerate() {
+        let current = states
+            .iter()
+            .map(|state| {
+                let own = note_cost(pitch, state);
+
+                if index == 0 {
+                    return (own + transition_cost(None, state), 0);
+                }
+
+                states
+                    .iter()
+                    .enumerate()
+                    .map(|(from, previous)| {
+                        (
+                            costs[index - 1][from].0 + own + transition_cost(Some(previous), state),
+                            from,
+                        )
+                    })
+                    .min()
+                    .unwrap()
+            })
+            .collect();
+
+        costs.push(current);
+    }
+
+    let mut notations = Vec::with_capacity(pitches.len());
+
+    if let Some(last) = costs.last() {
+        let mut best = (0..states.len())
+            .min_by_key(|&state| last[state].0)
+            .unwrap();
+
+        for index in (0..pitches.len()).rev() {
+            notations.push(states[best]);
+            best = costs[index][best].1;
+        }
+
+        notations.reverse();
+    }
+
+    ClefPlan { notations }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{plan_clefs, Clef, ClefPlanOptions, ClefSet, PitchClassUtils};
+
+    #[test]
+    fn no_flickering() {
+        // A single high note is not worth two clef changes
+        let pitches = [G.o(2), B.o(2), D.o(3), E.o(4), D.o(3), B.o(2), G.o(2)];
+
+        let options = ClefPlanOptions::new(ClefSet::from([Clef::BASS, Clef::TENOR]));
+        let plan = plan_clefs(&pitches, &options);
+
+        assert_eq!(plan.changes().len(), 1);
+        assert!(plan
+            .notations
+            .iter()
+            .all(|notation| notation.clef == Clef::BASS));
+
+        let twitchy = ClefPlanOptions {
+            switch_penalty: 0,
+            ..options
+        };
+
+        assert!(plan_clefs(&pitches, &twitchy).changes().len() > 1);
+    }
+
+    #[test]
+    fn ottavas() {
+        let pitches = [G.o(5), C.o(6), E.o(6), G.o(6), E.o(6), C.o(6), G.o(5)];
+
+        let mut options = ClefPlanOptions::new(ClefSet::from([Clef::TREBLE]));
+        let without = plan_clefs(&pitches, &options);
+
+        options.ottavas = true;
+        let with = plan_clefs(&pitches, &options);
+
+        assert!(with.notations.iter().any(|notation| notation.ottava == 1));
+        assert!(with.ledger_lines(&pitches) < without.ledger_lines(&pitches));
+        assert_eq!(with.changes()[1].1.to_string(), "treble, 8va");
+    }
+
+    #[test]
+    fn empty() {
+        let options = ClefPlanOptions::new(ClefSet::from([Clef::BASS]));
+
+        assert!(plan_clefs(&[], &options).notations.is_empty());
+    }
+}
@@ -0,0 +1,347 @@
+use crate::common::Scalar;
+use crate::pitch::{distance_of_pitch_set_to_pitch, Clef, Pitch, PitchSet};
+use crate::vertical::{Semitones, SemitonesFromC0};
+
+/// Restrictions and weights for splitting pitches across the two staves of a
+/// [grand staff](https://en.wikipedia.org/wiki/Staff_(music)#Grand_staff).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GrandStaffOptions {
+    pub upper: Clef,
+    pub lower: Clef,
+    /// The widest interval a single hand can play at once.
+    pub hand_span: Semitones,
+    /// Whether a hand may have notes written on the other hand's staff.
+    pub cross_staff: bool,
+    /// The cost of every ledger line.
+    pub ledger_line_penalty: Scalar,
+    /// The cost of every note written on the other hand's staff.
+    pub cross_staff_penalty: Scalar,
+    /// The cost of every voice that moves to the other staff from one set to the next.
+    pub staff_change_penalty: Scalar,
+}
+
+impl Default for GrandStaffOptions {
+    fn default() -> Self {
+        GrandStaffOptions {
+            upper: Clef::TREBLE,
+            lower: Clef::BASS,
+            hand_span: Semitones(14),
+            cross_staff: false,
+            ledger_line_penalty: 12,
+            cross_staff_penalty: 24,
+            staff_change_penalty: 18,
+        }
+    }
+}
+
+/// Which hand plays each pitch, and on which staff each pitch is written.
+///
+/// The hands never cross, and neither do the staves.
+/// When they differ, the pitches in between are written [cross-staff](GrandStaffSplit::cross_staff).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct GrandStaffSplit {
+    pub left_hand: PitchSet,
+    pub right_hand: PitchSet,
+    pub lower: PitchSet,
+    pub upper: PitchSet,
+}
+
+impl GrandStaffSplit {
+    /// Returns the pitches that are written on the staff of the other hand.
+    pub fn cross_staff(&self) -> PitchSet {
+        self.left_hand
+            .intersection(&self.upper)
+            .chain(self.right_hand.intersection(&self.lower))
+            .copied()
+            .collect()
+    }
+}
+
+/// Splits a set of pitches across the two staves of a grand staff, or `None` if the hands cannot reach them.
+///
+/// # Example
+///
+/// ```
+/// use solfege::pitch::{split_grand_staff, GrandStaffOptions, PitchClassUtils, PitchSet};
+/// use solfege::pitch::PitchRoot::{C, E, G};
+///
+/// let pitches = PitchSet::from([C.o(3), G.o(3), E.o(4), G.o(4), C.o(5)]);
+///
+/// let split = split_grand_staff(&pitches, &GrandStaffOptions::default()).unwrap();
+///
+/// assert_eq!(split.lower, PitchSet::from([C.o(3), G.o(3)]));
+/// assert_eq!(split.upper, PitchSet::from([E.o(4), G.o(4), C.o(5)]));
+/// ```
+pub fn split_grand_staff(
+    pitches: &PitchSet,
+    options: &GrandStaffOptions,
+) -> Option<GrandStaffSplit> {
+    split_grand_staff_sequence(std::slice::from_ref(pitches), options)
+        .map(|mut splits| splits.remove(0))
+}
+
+/// Splits a sequence of pitch sets across the two staves of a grand staff,
+/// minimising ledger lines while keeping voices on the same staff over time.
+///
+/// Returns `None` if the hands cannot reach one of the sets.
+pub fn split_grand_staff_sequence(
+    sets: &[PitchSet],
+    options: &GrandStaffOptions,
+) -> Option<Vec<GrandStaffSplit>> {
+    let candidates = sets
+        .iter()
+        .map(|set| candidate_splits(set, options))
+        .collect::<Vec<_>>();
+
+    if candidates.iter().any(|splits| splits.is_empty()) {
+        return None;
+    }
+
+    if candidates.is_empty() {
+        return Some(vec![]);
+    }
+
+    // The lowest cost of every candidate of the current set, with the candidate of the previous set it was reached from
+    let mut costs: Vec<Vec<(Scalar, usize)>> = vec![candidates[0]
+        .iter()
+        .map(|split| (notation_cost(split, options), 0))
+        .collect()];
+
+    for index in 1..candidates.len() {
+        let previous = &costs[index - 1];
+
+        let current = candidates[index]
+            .iter()
+            .map(|split| {
+                let own = notation_cost(split, options);
+
+                candidates[index - 1]
+                    .iter()
+                    .enumerate()
+                    .map(|(from, previous_split)| {
+                        (
+                            previous[from].0
+                                + own
+                                + continuity_cost(previous_split, split, options),
+                            from,
+                        )
+                    })
+                    .min()
+                    .unwrap()
+            })
+            .collect();
+
+        costs.push(current);
+    }
+
+    let mut best = (0..costs[costs.len() - 1].len())
+        .min_by_key(|&candidate| costs[costs.len() - 1][candidate].0)
+        .unwrap();
+
+    let mut splits = Vec::with_capacity(sets.len());
+
+    for index in (0..candidates.len()).rev() {
+        splits.push(candidates[index][best].clone());
+        best = costs[index][best].1;
+    }
+
+    splits.reverse();
+
+    Some(splits)
+}
+
+fn candidate_splits(set: &PitchSet, options: &GrandStaffOptions) -> Vec<GrandStaffSplit> {
+    // Split by how the pitches sound, as the spelling order puts B♯3 below C♭4
+    let mut pitches = set.iter().copied().collect::<Vec<_>>();
+    pitches.sort_by_key(|pitch| pitch.semitones_from_c0());
+    let mut splits = Vec::new();
+
+    for hand in 0..=pitches.len() {
+        let (left_hand, right_hand) = pitches.split_at(hand);
+
+        if span(left_hand) > options.hand_span || span(right_hand) > options.hand_span {
+            continue;
+        }
+
+        let staves = if options.cross_staff {
+            0..=pitches.len()
+        } else {
+            hand..=hand
+        };
+
+        for staff in staves {
+            let (lower, upper) = pitches.split_at(staff);
+
+            splits.push(GrandStaffSplit {
+                left_hand: left_hand.iter().copied().collect(),
+                right_hand: right_hand.iter().copied().collect(),
+                lower: lower.iter().copied().collect(),
+                upper: upper.iter().copied().collect(),
+            });
+        }
+    }
+
+    splits
+}
+
+/// Returns the distance between the outer pitches, which must be sorted by how they sound.
+fn span(pitches: &[Pitch]) -> Semitones {
+    match (pitches.first(), pitches.last()) {
+        (Some(low), Some(high)) => high.semitones_from_c0() - low.semitones_from_c0(),
+        _ => Semitones(0),
+    }
+}
+
+fn staff_cost(pitches: &PitchSet, clef: &Clef, options: &GrandStaffOptions) -> Scalar {
+    if pitches.is_empty() {
+        return 0;
+    }
+
+    let ledger_lines: Scalar = pitches
+        .iter()
+        .map(|pitch| clef.position_of(pitch).ledger_lines())
+        .sum();
+
+    ledger_lines * options.ledger_line_penalty
+        + distance_of_pitch_set_to_pitch(pitches, clef.center()).0
+}
+
+fn notation_cost(split: &GrandStaffSplit, options: &GrandStaffOptions) -> Scalar {
+    staff_cost(&split.lower, &options.lower, options)
+        + staff_cost(&split.upper, &options.upper, options)
+        + split.cross_staff().len() as Scalar * options.cross_staff_penalty
+}
+
+/// Penalises every pitch whose nearest pitch in the previous set was written on the other staff.
+fn continuity_cost(
+    previous: &GrandStaffSplit,
+    current: &GrandStaffSplit,
+    options: &GrandStaffOptions,
+) -> Scalar {
+    let nearest_on_upper = |pitch: &Pitch| {
+        previous
+            .lower
+            .iter()
+            .map(|other| (distance(pitch, other), false))
+            .chain(
+                previous
+                    .upper
+                    .iter()
+                    .map(|other| (distance(pitch, other), true)),
+            )
+            .min()
+            .map(|(_, upper)| upper)
+    };
+
+    let moved = current
+        .lower
+        .iter()
+        .filter(|pitch| nearest_on_upper(pitch) == Some(true))
+        .chain(
+            current
+                .upper
+                .iter()
+                .filter(|pitch| nearest_on_upper(pitch) == Some(false)),
+        )
+        .count();
+
+    moved as Scalar * options.staff_change_penalty
+}
+
+fn distance(a: &Pitch, b: &Pitch) -> Scalar {
+    (a.semitones_from_c0() - b.semitones_from_c0()).0.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{
+        split_grand_staff, split_grand_staff_sequence, GrandStaffOptions, PitchClassUtils,
+        PitchRootUtils, PitchSet,
+    };
+    use crate::vertical::Semitones;
+
+    #[test]
+    fn hand_span() {
+        let options = GrandStaffOptions::default();
+
+        // No split lets two hands reach three pitches that are each two octaves apart
+        let pitches = PitchSet::from([C.o(2), C.o(4), C.o(6)]);
+
+        assert_eq!(split_grand_staff(&pitches, &options), None);
+
+        let wide = GrandStaffOptions {
+            hand_span: Semitones(24),
+            ..options
+        };
+
+        assert!(split_grand_staff(&pitches, &wide).is_some());
+    }
+
+    #[test]
+    fn enharmonic_order() {
+        // B♯3 sounds above C♭4, so it belongs to the right hand when the hands can't share the pitches
+        let pitches = PitchSet::from([B.sharp().o(3), C.flat().o(4)]);
+
+        let options = GrandStaffOptions {
+            hand_span: Semitones(0),
+            ..GrandStaffOptions::default()
+        };
+
+        let split = split_grand_staff(&pitches, &options).unwrap();
+
+        assert_eq!(split.left_hand, PitchSet::from([C.flat().o(4)]));
+        assert_eq!(split.right_hand, PitchSet::from([B.sharp().o(3)]));
+    }
+
+    #[test]
+    fn cross_staff() {
+        // The right hand has to take the D3, which reads better on the bass staff
+        let pitches = PitchSet::from([C.o(2), G.o(2), D.o(3), B.o(3), D.o(4)]);
+
+        let options = GrandStaffOptions {
+            hand_span: Semitones(12),
+            cross_staff: true,
+            ..GrandStaffOptions::default()
+        };
+
+        let split = split_grand_staff(&pitches, &options).unwrap();
+
+        assert_eq!(split.left_hand, PitchSet::from([C.o(2), G.o(2)]));
+        assert_eq!(split.cross_staff(), PitchSet::from([D.o(3)]));
+        assert_eq!(split.lower, PitchSet::from([C.o(2), G.o(2), D.o(3)]));
+
+        let without = GrandStaffOptions {
+            cross_staff: false,
+            ..options
+        };
+
+        assert!(split_grand_staff(&pitches, &without)
+            .unwrap()
+            .cross_staff()
+            .is_empty());
+    }
+
+    #[test]
+    fn voices_stay_on_their_staff() {
+        let sets = [
+            PitchSet::from([G.o(3), C.o(5), E.o(5)]),
+            PitchSet::from([D.o(4), C.o(5), E.o(5)]),
+        ];
+
+        // The rising bass voice stays on the lower staff, although D4 alone would read better on the upper one
+        let splits = split_grand_staff_sequence(&sets, &GrandStaffOptions::default()).unwrap();
+
+        assert_eq!(splits[1].lower, PitchSet::from([D.o(4)]));
+
+        let loose = GrandStaffOptions {
+            staff_change_penalty: 0,
+            ..GrandStaffOptions::default()
+        };
+
+        let splits = split_grand_staff_sequence(&sets, &loose).unwrap();
+
+        assert!(splits[1].lower.is_empty());
+        assert_eq!(split_grand_staff_sequence(&[], &loose), Some(vec![]));
+    }
+}
@@ -1,19 +1,28 @@
 mod accidental;
 mod clef;
+mod clef_changes;
 mod dyads;
+mod grand_staff;
 mod octave;
+#[allow(clippy::module_inception)]
 mod pitch;
 mod pitch_class;
 mod pitch_root;
 mod sets;
+mod sonorities;
+mod staff;
 mod utils;
 
 pub use self::accidental::*;
 pub use self::clef::*;
+pub use self::clef_changes::*;
 pub use self::dyads::*;
+pub use self::grand_staff::*;
 pub use self::octave::*;
 pub use self::pitch::*;
 pub use self::pitch_class::*;
 pub use self::pitch_root::*;
 pub use self::sets::*;
+pub use self::sonorities::*;
+pub use self::staff::*;
 pub use self::utils::*;
@@ -1,5 +1,5 @@
 use crate::interval::Interval;
-use crate::pitch::{Octave, PitchClass};
+use crate::pitch::{Accidental, Octave, PitchClass, PitchRoot};
 use crate::vertical::{Octaves, Semitones, SemitonesFromC, SemitonesFromC0, Steps, StepsFromC, StepsFromC0, TransposedBy};
 use serde::{Serialize, Serializer};
 use std::fmt::{Display, Formatter};
@@ -20,6 +20,35 @@ pub struct Pitch {
     pub class: PitchClass,
 }
 
+impl Pitch {
+    /// Creates the pitch that lies a number of steps and semitones above C0,
+    /// spelling it with whatever accidental is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::pitch::{Pitch, PitchClassUtils, PitchRootUtils};
+    /// use solfege::pitch::PitchRoot::{D, E};
+    /// use solfege::vertical::{Semitones, Steps};
+    ///
+    /// assert_eq!(Pitch::from_c0(Steps(30), Semitones(51)), E.flat().o(4));
+    /// assert_eq!(Pitch::from_c0(Steps(29), Semitones(51)), D.sharp().o(4));
+    /// ```
+    pub fn from_c0(steps: Steps, semitones: Semitones) -> Self {
+        let octave = Octave::from(steps.0.div_euclid(7));
+        let root = PitchRoot::from(steps);
+
+        let accidental = Accidental {
+            offset: semitones - octave.semitones_from_c0() - root.semitones_from_c(),
+        };
+
+        Pitch {
+            octave,
+            class: PitchClass { root, accidental },
+        }
+    }
+}
+
 impl StepsFromC0 for Pitch {
     fn steps_from_c0(&self) -> Steps {
         self.octave.steps_from_c0() + self.class.steps_from_c()
@@ -60,7 +89,7 @@ impl TryFrom<&str> for Pitch {
     type Error = &'static str;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() < 3 {
+        if value.len() < 2 {
             return Err("String is too short to be a Pitch");
         }
 
@@ -93,7 +122,7 @@ mod test {
     use crate::interval::IntervalRoot::*;
     use crate::pitch::PitchRoot::*;
     use crate::pitch::PitchRootUtils;
-    use crate::pitch::{Pitch, PitchClassUtils};
+    use crate::pitch::PitchClassUtils;
     use crate::vertical::Semitones;
     use crate::vertical::SemitonesFromC0;
     use crate::vertical::Steps;
@@ -125,6 +154,8 @@ mod test {
 
     #[test]
     fn from_str() {
-        assert_eq!("C♮4".try_into(), Ok(C.o(4)))
+        assert_eq!("C♮4".try_into(), Ok(C.o(4)));
+        assert_eq!("Eb3".try_into(), Ok(E.flat().o(3)));
+        assert_eq!("G5".try_into(), Ok(G.o(5)));
     }
 }
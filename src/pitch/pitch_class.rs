@@ -1,3 +1,4 @@
+use crate::common::Scalar;
 use crate::interval::IntervalClass;
 use crate::pitch::{Accidental, PitchRoot};
 use crate::vertical::{Semitones, SemitonesFromC, Steps, StepsFromC, TransposedBy};
@@ -19,6 +20,19 @@ pub struct PitchClass {
     pub accidental: Accidental,
 }
 
+impl PitchClass {
+    /// Returns the [integer notation](https://en.wikipedia.org/wiki/Pitch_class#Integer_notation) of the pitch class,
+    /// from 0 (C) to 11 (B), regardless of its spelling.
+    pub fn integer_notation(&self) -> Scalar {
+        self.semitones_from_c().0.rem_euclid(12)
+    }
+
+    /// Returns whether two pitch classes sound the same, regardless of their spelling.
+    pub fn is_enharmonic_to(&self, other: &PitchClass) -> bool {
+        self.integer_notation() == other.integer_notation()
+    }
+}
+
 impl StepsFromC for PitchClass {
     fn steps_from_c(&self) -> Steps {
         self.root.steps_from_c()
@@ -81,11 +95,11 @@ impl TryFrom<&str> for PitchClass {
     type Error = &'static str;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() < 2 {
+        if value.is_empty() {
             return Err("String is too short to be a Pitch Class");
         }
 
-        let root = PitchRoot::try_from(&value[0..1])?;
+        let root = PitchRoot::try_from(value.get(0..1).ok_or("Could not recognise pitch root")?)?;
         let accidental = Accidental::try_from(&value[1..])?;
 
         Ok(PitchClass { root, accidental })
@@ -144,8 +158,24 @@ mod test {
         assert_eq!(B.flat().transposed_by(&minor(Third)), D.flat());
     }
 
+    #[test]
+    fn integer_notation() {
+        assert_eq!(C.natural().integer_notation(), 0);
+        assert_eq!(C.flat().integer_notation(), 11);
+        assert_eq!(B.sharp().integer_notation(), 0);
+        assert!(G.sharp().is_enharmonic_to(&A.flat()));
+        assert!(!G.sharp().is_enharmonic_to(&A.natural()));
+    }
+
     #[test]
     fn display() {
         assert_eq!(F.sharp().to_string(), "F♯");
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("F♯".try_into(), Ok(F.sharp()));
+        assert_eq!("Bb".try_into(), Ok(B.flat()));
+        assert_eq!("C".try_into(), Ok(C.natural()));
+    }
 }
@@ -146,7 +146,7 @@ pub fn tonal_pitch_class_set(depth: usize) -> PitchClassSet {
 /// ```
 impl TransposedBy<&IntervalClass> for PitchClassSet {
     fn transposed_by(&self, delta: &IntervalClass) -> Self {
-        self.into_iter()
+        self.iter()
             .map(|class| class.transposed_by(delta))
             .collect::<PitchClassSet>()
     }
@@ -154,7 +154,7 @@ impl TransposedBy<&IntervalClass> for PitchClassSet {
 
 impl TransposedBy<&Interval> for PitchSet {
     fn transposed_by(&self, delta: &Interval) -> Self {
-        self.into_iter()
+        self.iter()
             .map(|pitch| pitch.transposed_by(delta))
             .collect::<PitchSet>()
     }
@@ -182,7 +182,7 @@ pub fn distance_of_pitch_set_to_pitch(set: &PitchSet, pitch: Pitch) -> Semitones
 
     set.iter()
         .map(|pitch_in_set| {
-            Interval::from(&PitchDyad::from((pitch, pitch_in_set.clone()))).in_semitones()
+            Interval::from(&PitchDyad::from((pitch, *pitch_in_set))).in_semitones()
         })
         .fold(Semitones(0), |a, b| a + b)
 }
@@ -0,0 +1,149 @@
+use crate::harmony::{Dyad, Sonority};
+use crate::interval::{Interval, IntervalClass};
+use crate::pitch::{Pitch, PitchClass, PitchRoot, PitchSet};
+use std::collections::BTreeMap;
+
+pub type PitchSonority = Sonority<Pitch>;
+pub type PitchClassSonority = Sonority<PitchClass>;
+pub type PitchRootSonority = Sonority<PitchRoot>;
+
+impl PitchSonority {
+    /// Counts the spelled intervals between every pair of pitches, as given by [dyads](Sonority::dyads).
+    ///
+    /// Compound intervals are reduced to simple ones, so an octave is counted as a unison.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::interval::{major, minor, perfect};
+    /// use solfege::interval::IntervalRoot::{Fifth, Third};
+    /// use solfege::pitch::{PitchClassUtils, PitchSonority};
+    /// use solfege::pitch::PitchRoot::{C, E, G};
+    ///
+    /// let inventory = PitchSonority::from([C.o(4), E.o(4), G.o(4)]).interval_inventory();
+    ///
+    /// assert_eq!(inventory[&major(Third)], 1);
+    /// assert_eq!(inventory[&minor(Third)], 1);
+    /// assert_eq!(inventory[&perfect(Fifth)], 1);
+    /// ```
+    pub fn interval_inventory(&self) -> BTreeMap<IntervalClass, usize> {
+        let mut inventory = BTreeMap::new();
+
+        for dyad in self.dyads() {
+            *inventory.entry(Interval::from(&dyad).class).or_insert(0) += 1;
+        }
+
+        inventory
+    }
+
+    /// Returns the intervals between neighbouring pitches, from the bass to the top.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::interval::{major, minor, perfect, IntervalClassUtils};
+    /// use solfege::interval::IntervalRoot::{Fifth, Sixth, Third};
+    /// use solfege::pitch::{PitchClassUtils, PitchSonority};
+    /// use solfege::pitch::PitchRoot::{C, E, G};
+    ///
+    /// let sonority = PitchSonority::from([C.o(3), G.o(3), E.o(4)]);
+    ///
+    /// assert_eq!(
+    ///     sonority.adjacent_intervals(),
+    ///     vec![perfect(Fifth).simple(), major(Sixth).simple()],
+    /// );
+    /// ```
+    pub fn adjacent_intervals(&self) -> Vec<Interval> {
+        self.values
+            .windows(2)
+            .map(|pair| Interval::from(&Dyad::from((pair[0], pair[1]))))
+            .collect()
+    }
+}
+
+impl From<&PitchSet> for PitchSonority {
+    fn from(pitch_set: &PitchSet) -> Self {
+        Self {
+            values: pitch_set.iter().copied().collect(),
+        }
+    }
+}
+
+impl From<&PitchSonority> for PitchClassSonority {
+    fn from(pitch_sonority: &PitchSonority) -> Self {
+        PitchClassSonority::from(
+            pitch_sonority
+                .values
+                .iter()
+                .map(|pitch| pitch.class)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl From<&PitchSonority> for PitchRootSonority {
+    fn from(pitch_sonority: &PitchSonority) -> Self {
+        PitchRootSonority::from(
+            pitch_sonority
+                .values
+                .iter()
+                .map(|pitch| pitch.class.root)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl From<&PitchClassSonority> for PitchRootSonority {
+    fn from(pitch_class_sonority: &PitchClassSonority) -> Self {
+        PitchRootSonority::from(
+            pitch_class_sonority
+                .values
+                .iter()
+                .map(|class| class.root)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::IntervalRoot::{Fifth, Third, Unison};
+    use crate::interval::{minor, perfect};
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{
+        PitchClassSonority, PitchClassUtils, PitchRootSonority, PitchRootUtils, PitchSonority,
+    };
+
+    #[test]
+    fn conversions() {
+        let sonority = PitchSonority::from([G.o(3), C.o(4), E.flat().o(4), G.o(4)]);
+
+        assert_eq!(
+            PitchClassSonority::from(&sonority).values,
+            vec![C.natural(), E.flat(), G.natural(), G.natural()],
+        );
+        assert_eq!(PitchRootSonority::from(&sonority).values, vec![C, E, G, G]);
+        assert_eq!(
+            PitchRootSonority::from(&PitchClassSonority::from(&sonority)),
+            PitchRootSonority::from(&sonority),
+        );
+    }
+
+    #[test]
+    fn interval_inventory() {
+        let inventory =
+            PitchSonority::from([G.o(3), C.o(4), E.flat().o(4), G.o(4)]).interval_inventory();
+
+        assert_eq!(inventory[&perfect(Unison)], 1);
+        assert_eq!(inventory[&minor(Third)], 1);
+        assert_eq!(inventory[&perfect(Fifth)], 1);
+        assert_eq!(inventory.values().sum::<usize>(), 6);
+
+        let doubled = PitchSonority::from([C.o(3), C.o(4), E.o(4), E.o(4)]);
+
+        assert_eq!(
+            doubled.interval_inventory().values().sum::<usize>(),
+            doubled.dyads().len()
+        );
+    }
+}
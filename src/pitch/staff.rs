@@ -0,0 +1,194 @@
+use crate::common::Scalar;
+use crate::pitch::{Clef, Octave, Pitch, PitchRoot};
+use crate::vertical::{Steps, StepsFromC0};
+use std::fmt::{Display, Formatter};
+
+/// Where a position lies relative to the five lines of a staff.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum StaffPlacement {
+    Below,
+    Within,
+    Above,
+}
+
+/// A vertical position on a five-line [staff](https://en.wikipedia.org/wiki/Staff_(music)),
+/// counted in lines and spaces from the bottom line, which is 0.
+///
+/// Even positions are lines, and odd positions are spaces; the top line is 8.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::pitch::{Clef, Octave, PitchClassUtils, StaffPlacement, StaffPosition};
+/// use solfege::pitch::PitchRoot::{C, E, G};
+///
+/// let middle_c = Clef::TREBLE.position_of(&C.o(4));
+///
+/// assert_eq!(middle_c, StaffPosition(-2));
+/// assert_eq!(middle_c.ledger_lines(), 1);
+/// assert_eq!(middle_c.placement(), StaffPlacement::Below);
+///
+/// assert_eq!(Clef::ALTO.position_of(&C.o(4)).line(), Some(3));
+/// assert_eq!(Clef::BASS.position_of(&E.o(3)).space(), Some(3));
+/// assert_eq!(Clef::TENOR.pitch_at(StaffPosition(10)), (G, Octave::from(4)));
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct StaffPosition(pub Scalar);
+
+pub const MIDDLE_LINE: StaffPosition = StaffPosition(4);
+pub const TOP_LINE: StaffPosition = StaffPosition(8);
+
+impl StaffPosition {
+    pub fn is_line(&self) -> bool {
+        self.0.rem_euclid(2) == 0
+    }
+
+    pub fn is_space(&self) -> bool {
+        !self.is_line()
+    }
+
+    /// Returns the staff line the position is on, from 1 at the bottom to 5 at the top.
+    pub fn line(&self) -> Option<Scalar> {
+        (self.is_line() && self.placement() == StaffPlacement::Within).then_some(self.0 / 2 + 1)
+    }
+
+    /// Returns the space the position is in, from 1 at the bottom to 4 at the top.
+    pub fn space(&self) -> Option<Scalar> {
+        (self.is_space() && self.placement() == StaffPlacement::Within).then_some(self.0 / 2 + 1)
+    }
+
+    pub fn placement(&self) -> StaffPlacement {
+        if self.0 < 0 {
+            StaffPlacement::Below
+        } else if self.0 > TOP_LINE.0 {
+            StaffPlacement::Above
+        } else {
+            StaffPlacement::Within
+        }
+    }
+
+    /// Returns the number of ledger lines needed to reach the position.
+    pub fn ledger_lines(&self) -> Scalar {
+        match self.placement() {
+            StaffPlacement::Below => -self.0 / 2,
+            StaffPlacement::Within => 0,
+            StaffPlacement::Above => (self.0 - TOP_LINE.0) / 2,
+        }
+    }
+}
+
+impl Display for StaffPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.line(), self.space()) {
+            (Some(line), _) => write!(f, "line {}", line),
+            (_, Some(space)) => write!(f, "space {}", space),
+            _ => {
+                let side = match self.placement() {
+                    StaffPlacement::Below => "below",
+                    _ => "above",
+                };
+
+                match (self.is_line(), self.ledger_lines()) {
+                    (true, ledger_lines) => write!(f, "ledger line {} {}", ledger_lines, side),
+                    (false, 0) => write!(f, "space {} the staff", side),
+                    (false, ledger_lines) => {
+                        write!(f, "space {} ledger line {}", side, ledger_lines)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Maps between pitches and staff positions.
+///
+/// Octave-transposing clefs map the sounding pitch, so that C4 is written an octave higher under a treble 8vb clef.
+impl Clef {
+    /// Returns the position at which a pitch is written, regardless of its accidental.
+    pub fn position_of(&self, pitch: &Pitch) -> StaffPosition {
+        StaffPosition((pitch.steps_from_c0() - self.bottom_line()).0)
+    }
+
+    /// Returns the root and octave of the pitches written at a position.
+    pub fn pitch_at(&self, position: StaffPosition) -> (PitchRoot, Octave) {
+        let steps = self.bottom_line() + Steps(position.0);
+
+        (PitchRoot::from(steps), Octave::from(steps.0.div_euclid(7)))
+    }
+
+    fn bottom_line(&self) -> Steps {
+        self.reference().steps_from_c0() - Steps((self.line - 1) * 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{
+        Clef, Pitch, PitchClassUtils, PitchRootUtils, StaffPlacement, StaffPosition,
+    };
+
+    #[test]
+    fn positions() {
+        assert_eq!(Clef::TREBLE.position_of(&E.o(4)), StaffPosition(0));
+        assert_eq!(Clef::TREBLE.position_of(&F.o(5)), StaffPosition(8));
+        assert_eq!(Clef::BASS.position_of(&G.o(2)), StaffPosition(0));
+        assert_eq!(Clef::TREBLE_8VB.position_of(&C.o(4)), StaffPosition(5));
+
+        // Accidentals do not move a note
+        assert_eq!(
+            Clef::TREBLE.position_of(&C.flat().o(5)),
+            Clef::TREBLE.position_of(&C.sharp().o(5))
+        );
+        assert_ne!(
+            Clef::TREBLE.position_of(&B.sharp().o(4)),
+            Clef::TREBLE.position_of(&C.o(5))
+        );
+    }
+
+    #[test]
+    fn ledger_lines() {
+        let cases = [
+            (A.o(5), 1, StaffPlacement::Above),
+            (G.o(5), 0, StaffPlacement::Above),
+            (B.o(5), 1, StaffPlacement::Above),
+            (C.o(6), 2, StaffPlacement::Above),
+            (D.o(4), 0, StaffPlacement::Below),
+            (A.o(3), 2, StaffPlacement::Below),
+            (E.o(4), 0, StaffPlacement::Within),
+        ];
+
+        for (pitch, ledger_lines, placement) in cases {
+            let position = Clef::TREBLE.position_of(&pitch);
+
+            assert_eq!(position.ledger_lines(), ledger_lines, "{}", pitch);
+            assert_eq!(position.placement(), placement, "{}", pitch);
+        }
+    }
+
+    #[test]
+    fn reverse_mapping() {
+        for clef in [Clef::TREBLE, Clef::SOPRANO, Clef::BARITONE, Clef::BASS_8VB] {
+            for position in -6..=14 {
+                let (root, octave) = clef.pitch_at(StaffPosition(position));
+                let pitch = Pitch {
+                    octave,
+                    class: root.natural(),
+                };
+
+                assert_eq!(clef.position_of(&pitch), StaffPosition(position));
+            }
+        }
+
+        assert_eq!(Clef::ALTO.pitch_at(StaffPosition(-1)).0, E);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(StaffPosition(0).to_string(), "line 1");
+        assert_eq!(StaffPosition(7).to_string(), "space 4");
+        assert_eq!(StaffPosition(-1).to_string(), "space below the staff");
+        assert_eq!(StaffPosition(-2).to_string(), "ledger line 1 below");
+        assert_eq!(StaffPosition(11).to_string(), "space above ledger line 1");
+    }
+}
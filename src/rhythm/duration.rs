@@ -0,0 +1,237 @@
+use crate::common::Scalar;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// An exact [duration](https://en.wikipedia.org/wiki/Duration_(music)) as a fraction of a whole note.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::rhythm::Duration;
+///
+/// let dotted_quarter = Duration::new(3, 8);
+///
+/// assert_eq!(dotted_quarter + Duration::new(1, 8), Duration::new(1, 2));
+/// assert_eq!(Duration::new(2, 6), Duration::new(1, 3));
+/// assert_eq!(dotted_quarter.to_string(), "3/8");
+/// assert!(Duration::new(1, 3) > Duration::new(1, 4));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Duration {
+    numerator: Scalar,
+    denominator: Scalar,
+}
+
+impl Duration {
+    pub const ZERO: Duration = Duration {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    pub const WHOLE: Duration = Duration {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Creates a duration of a number of whole notes, reduced to its lowest terms.
+    pub fn new(numerator: Scalar, denominator: Scalar) -> Self {
+        assert_ne!(
+            denominator, 0,
+            "A duration can't have a denominator of zero"
+        );
+
+        let divisor = greatest_common_divisor(numerator, denominator) * denominator.signum();
+
+        Duration {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> Scalar {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> Scalar {
+        self.denominator
+    }
+
+    /// Returns whether the duration can be written without tuplets, as its denominator is a power of two.
+    pub fn is_binary(&self) -> bool {
+        self.denominator & (self.denominator - 1) == 0
+    }
+}
+
+fn greatest_common_divisor(a: Scalar, b: Scalar) -> Scalar {
+    match b {
+        0 => a.abs().max(1),
+        _ => greatest_common_divisor(b, a % b),
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.denominator + rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.denominator - rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Mul<Scalar> for Duration {
+    type Output = Self;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        // Reducing first keeps the product from overflowing when it can be avoided
+        let divisor = greatest_common_divisor(rhs, self.denominator);
+
+        Self::new(self.numerator * (rhs / divisor), self.denominator / divisor)
+    }
+}
+
+/// Panics if the divisor is zero.
+impl Div<Scalar> for Duration {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Scalar) -> Self::Output {
+        assert_ne!(rhs, 0, "A duration can't be divided by zero");
+
+        // Reducing first keeps the product from overflowing when it can be avoided
+        let divisor = greatest_common_divisor(self.numerator, rhs);
+
+        Self::new(self.numerator / divisor, self.denominator * (rhs / divisor))
+    }
+}
+
+impl Mul for Duration {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self * rhs.numerator / rhs.denominator
+    }
+}
+
+/// Panics if the divisor is zero.
+impl Div for Duration {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        assert_ne!(rhs, Self::ZERO, "A duration can't be divided by zero");
+
+        self * rhs.denominator / rhs.numerator
+    }
+}
+
+impl Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |sum, duration| sum + duration)
+    }
+}
+
+impl Ord for Duration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            _ => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rhythm::Duration;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(
+            Duration::new(1, 4) + Duration::new(1, 6),
+            Duration::new(5, 12)
+        );
+        assert_eq!(
+            Duration::new(1, 4) - Duration::new(1, 2),
+            Duration::new(-1, 4)
+        );
+        assert_eq!(Duration::new(1, 8) * 3, Duration::new(3, 8));
+        assert_eq!(Duration::new(1, 4) / 3, Duration::new(1, 12));
+        assert_eq!(
+            Duration::new(1, 4) * Duration::new(3, 2),
+            Duration::new(3, 8)
+        );
+        assert_eq!(
+            Duration::new(3, 8) / Duration::new(3, 2),
+            Duration::new(1, 4)
+        );
+        assert_eq!(
+            [
+                Duration::new(1, 2),
+                Duration::new(1, 4),
+                Duration::new(1, 4)
+            ]
+            .into_iter()
+            .sum::<Duration>(),
+            Duration::WHOLE,
+        );
+    }
+
+    #[test]
+    fn reduction() {
+        assert_eq!(Duration::new(4, 8).denominator(), 2);
+        assert_eq!(Duration::new(1, -4), Duration::new(-1, 4));
+        assert_eq!(Duration::new(0, 5), Duration::ZERO);
+    }
+
+    #[test]
+    fn binary() {
+        assert!(Duration::new(3, 16).is_binary());
+        assert!(!Duration::new(1, 12).is_binary());
+    }
+
+    #[test]
+    fn no_needless_overflow() {
+        let tiny = Duration::new(1, i32::MAX);
+
+        assert_eq!(tiny * i32::MAX, Duration::WHOLE);
+        assert_eq!(Duration::new(i32::MAX, 1) / i32::MAX, Duration::WHOLE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = Duration::new(1, 4) / 0;
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero_duration() {
+        let _ = Duration::new(1, 4) / Duration::ZERO;
+    }
+}
@@ -0,0 +1,235 @@
+use crate::rhythm::{Duration, Note, Rest, TimeSignature};
+
+/// A note or rest, in order within a line of music.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Event {
+    /// A note, which may be tied to the next note.
+    Note {
+        note: Note,
+        tie: bool,
+    },
+    Rest(Rest),
+}
+
+impl Event {
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Note { note, .. } => note.duration,
+            Self::Rest(rest) => rest.duration,
+        }
+    }
+
+    /// Returns the same event with a different duration.
+    fn with_duration(&self, duration: Duration, tie: bool) -> Self {
+        match *self {
+            Self::Note { note, .. } => Self::Note {
+                note: Note { duration, ..note },
+                tie,
+            },
+            Self::Rest(_) => Self::Rest(Rest { duration }),
+        }
+    }
+}
+
+impl From<Note> for Event {
+    fn from(note: Note) -> Self {
+        Event::Note { note, tie: false }
+    }
+}
+
+impl From<Rest> for Event {
+    fn from(rest: Rest) -> Self {
+        Event::Rest(rest)
+    }
+}
+
+/// A [measure](https://en.wikipedia.org/wiki/Bar_(music)) of events in a time signature.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Measure {
+    pub time_signature: TimeSignature,
+    pub events: Vec<Event>,
+}
+
+impl Measure {
+    /// Returns the total duration of the events.
+    pub fn duration(&self) -> Duration {
+        self.events.iter().map(|event| event.duration()).sum()
+    }
+
+    /// Checks whether the events exactly fill the measure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::pitch::PitchClassUtils;
+    /// use solfege::pitch::PitchRoot::{C, D};
+    /// use solfege::rhythm::{Duration, Event, Measure, Note, Rest, TimeSignature};
+    ///
+    /// let mut measure = Measure {
+    ///     time_signature: TimeSignature::new(3, 4),
+    ///     events: vec![
+    ///         Event::from(Note { pitch: C.o(4), duration: Duration::new(1, 2) }),
+    ///         Event::from(Rest { duration: Duration::new(1, 8) }),
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(measure.validate(), Err("The measure is too short"));
+    ///
+    /// measure.events.push(Event::from(Note { pitch: D.o(4), duration: Duration::new(1, 8) }));
+    ///
+    /// assert_eq!(measure.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let duration = self.duration();
+        let expected = self.time_signature.duration();
+
+        if duration < expected {
+            Err("The measure is too short")
+        } else if duration > expected {
+            Err("The measure is too long")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Divides events into measures, splitting notes over barlines with ties and rests without.
+/// Each measure takes the next time signature, and the last time signature holds for the remaining measures.
+/// The last measure may be incomplete.
+///
+/// # Example
+///
+/// ```
+/// use solfege::pitch::PitchClassUtils;
+/// use solfege::pitch::PitchRoot::{C, D};
+/// use solfege::rhythm::{split_into_measures, Duration, Event, Note, TimeSignature};
+///
+/// let events = [
+///     Event::from(Note { pitch: C.o(4), duration: Duration::new(1, 2) }),
+///     Event::from(Note { pitch: D.o(4), duration: Duration::new(1, 2) }),
+/// ];
+///
+/// let measures = split_into_measures(&events, &[TimeSignature::new(3, 4)]);
+///
+/// assert_eq!(measures.len(), 2);
+/// assert_eq!(
+///     measures[0].events[1],
+///     Event::Note { note: Note { pitch: D.o(4), duration: Duration::new(1, 4) }, tie: true },
+/// );
+/// assert_eq!(measures[1].duration(), Duration::new(1, 4));
+/// ```
+pub fn split_into_measures(events: &[Event], time_signatures: &[TimeSignature]) -> Vec<Measure> {
+    assert!(
+        !time_signatures.is_empty(),
+        "At least one time signature is needed"
+    );
+
+    let time_signature =
+        |index: usize| time_signatures[index.min(time_signatures.len() - 1)].clone();
+
+    let mut measures = vec![Measure {
+        time_signature: time_signature(0),
+        events: vec![],
+    }];
+
+    for event in events {
+        let mut remaining = event.duration();
+
+        while remaining > Duration::ZERO {
+            let measure = measures.last_mut().expect("There is always a measure");
+
+            let space = measure.time_signature.duration() - measure.duration();
+
+            if space == Duration::ZERO {
+                let index = measures.len();
+
+                measures.push(Measure {
+                    time_signature: time_signature(index),
+                    events: vec![],
+                });
+
+                continue;
+            }
+
+            let duration = remaining.min(space);
+            remaining = remaining - duration;
+
+            let tie = match event {
+                Event::Note { tie, .. } => remaining > Duration::ZERO || *tie,
+                Event::Rest(_) => false,
+            };
+
+            measure.events.push(event.with_duration(duration, tie));
+        }
+    }
+
+    measures
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pitch::PitchClassUtils;
+    use crate::pitch::PitchRoot::{C, E};
+    use crate::rhythm::{split_into_measures, Duration, Event, Note, Rest, TimeSignature};
+
+    #[test]
+    fn changing_meters() {
+        let events = vec![
+            Event::from(Note {
+                pitch: C.o(4),
+                duration: Duration::new(1, 2)
+            });
+            4
+        ];
+
+        let meters = [TimeSignature::new(2, 4), TimeSignature::new(3, 8)];
+
+        let measures = split_into_measures(&events, &meters);
+
+        assert_eq!(measures[0].time_signature, TimeSignature::new(2, 4));
+        assert_eq!(measures[1].time_signature, TimeSignature::new(3, 8));
+        assert_eq!(measures[4].time_signature, TimeSignature::new(3, 8));
+        assert!(measures.iter().all(|measure| measure.validate().is_ok()));
+        assert_eq!(measures.len(), 5);
+    }
+
+    #[test]
+    fn long_notes_and_rests() {
+        let events = [
+            Event::from(Rest {
+                duration: Duration::new(5, 4),
+            }),
+            Event::from(Note {
+                pitch: E.o(4),
+                duration: Duration::new(9, 4),
+            }),
+        ];
+
+        let measures = split_into_measures(&events, &[TimeSignature::new(4, 4)]);
+
+        assert_eq!(measures.len(), 4);
+        assert_eq!(
+            measures[1].events,
+            vec![
+                Event::from(Rest {
+                    duration: Duration::new(1, 4)
+                }),
+                Event::Note {
+                    note: Note {
+                        pitch: E.o(4),
+                        duration: Duration::new(3, 4)
+                    },
+                    tie: true
+                },
+            ]
+        );
+        assert_eq!(
+            measures[3].events,
+            vec![Event::from(Note {
+                pitch: E.o(4),
+                duration: Duration::new(1, 2)
+            })]
+        );
+        assert_eq!(measures[3].validate(), Err("The measure is too short"));
+    }
+}
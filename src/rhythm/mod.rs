@@ -0,0 +1,11 @@
+mod duration;
+mod measure;
+mod note;
+mod note_value;
+mod time_signature;
+
+pub use self::duration::*;
+pub use self::measure::*;
+pub use self::note::*;
+pub use self::note_value::*;
+pub use self::time_signature::*;
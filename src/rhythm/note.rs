@@ -0,0 +1,94 @@
+use crate::pitch::Pitch;
+use crate::rhythm::{Duration, RhythmicValue};
+use std::fmt::{Display, Formatter};
+
+/// A [note](https://en.wikipedia.org/wiki/Musical_note): a pitch sounding for a duration.
+///
+/// # Example
+///
+/// ```
+/// use solfege::pitch::PitchClassUtils;
+/// use solfege::pitch::PitchRoot::C;
+/// use solfege::rhythm::{Duration, Note, NoteValue, RhythmicValue};
+///
+/// let note = Note { pitch: C.o(4), duration: Duration::new(1, 4) };
+/// let tied = note.tied_to(&Note { pitch: C.o(4), duration: Duration::new(1, 16) });
+///
+/// assert_eq!(tied.duration, Duration::new(5, 16));
+/// assert_eq!(
+///     tied.values(),
+///     Some(vec![RhythmicValue::from(NoteValue::Quarter), RhythmicValue::from(NoteValue::Sixteenth)]),
+/// );
+/// assert_eq!(tied.to_string(), "C♮4 5/16");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Note {
+    pub pitch: Pitch,
+    pub duration: Duration,
+}
+
+impl Note {
+    /// Returns the written values that are tied together to make up the note.
+    pub fn values(&self) -> Option<Vec<RhythmicValue>> {
+        RhythmicValue::tied(self.duration)
+    }
+
+    /// Ties the note to a following note of the same pitch.
+    pub fn tied_to(&self, next: &Note) -> Note {
+        assert_eq!(
+            self.pitch, next.pitch,
+            "Only notes of the same pitch can be tied"
+        );
+
+        Note {
+            pitch: self.pitch,
+            duration: self.duration + next.duration,
+        }
+    }
+}
+
+impl Display for Note {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.pitch, self.duration)
+    }
+}
+
+/// A [rest](https://en.wikipedia.org/wiki/Rest_(music)): a duration of silence.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Rest {
+    pub duration: Duration,
+}
+
+impl Rest {
+    pub fn values(&self) -> Option<Vec<RhythmicValue>> {
+        RhythmicValue::tied(self.duration)
+    }
+}
+
+impl Display for Rest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rest {}", self.duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pitch::PitchClassUtils;
+    use crate::pitch::PitchRoot::{C, D};
+    use crate::rhythm::{Duration, Note};
+
+    #[test]
+    #[should_panic]
+    fn ties_need_the_same_pitch() {
+        let quarter = Duration::new(1, 4);
+
+        Note {
+            pitch: C.o(4),
+            duration: quarter,
+        }
+        .tied_to(&Note {
+            pitch: D.o(4),
+            duration: quarter,
+        });
+    }
+}
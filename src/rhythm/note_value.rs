@@ -0,0 +1,336 @@
+use crate::common::Scalar;
+use crate::rhythm::Duration;
+use std::fmt::{Display, Formatter};
+
+/// The written [note value](https://en.wikipedia.org/wiki/Note_value), from a whole note to a 128th note.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum NoteValue {
+    Whole,
+    Half,
+    Quarter,
+    Eighth,
+    Sixteenth,
+    ThirtySecond,
+    SixtyFourth,
+    HundredTwentyEighth,
+}
+
+pub const NOTE_VALUES: [NoteValue; 8] = [
+    NoteValue::Whole,
+    NoteValue::Half,
+    NoteValue::Quarter,
+    NoteValue::Eighth,
+    NoteValue::Sixteenth,
+    NoteValue::ThirtySecond,
+    NoteValue::SixtyFourth,
+    NoteValue::HundredTwentyEighth,
+];
+
+impl NoteValue {
+    /// Returns how many of the value fit in a whole note.
+    pub fn per_whole(&self) -> Scalar {
+        match *self {
+            Self::Whole => 1,
+            Self::Half => 2,
+            Self::Quarter => 4,
+            Self::Eighth => 8,
+            Self::Sixteenth => 16,
+            Self::ThirtySecond => 32,
+            Self::SixtyFourth => 64,
+            Self::HundredTwentyEighth => 128,
+        }
+    }
+}
+
+impl From<NoteValue> for Duration {
+    fn from(value: NoteValue) -> Self {
+        Duration::new(1, value.per_whole())
+    }
+}
+
+impl Display for NoteValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Whole => "whole",
+            Self::Half => "half",
+            Self::Quarter => "quarter",
+            Self::Eighth => "eighth",
+            Self::Sixteenth => "16th",
+            Self::ThirtySecond => "32nd",
+            Self::SixtyFourth => "64th",
+            Self::HundredTwentyEighth => "128th",
+        })
+    }
+}
+
+/// A [tuplet](https://en.wikipedia.org/wiki/Tuplet) plays a number of notes in the time of another number of notes.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Tuplet {
+    pub actual: Scalar,
+    pub normal: Scalar,
+}
+
+pub const TRIPLET: Tuplet = Tuplet {
+    actual: 3,
+    normal: 2,
+};
+
+impl Display for Tuplet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.actual, self.normal)
+    }
+}
+
+/// A note value with any dots and tuplet, as it would be written.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::rhythm::{Duration, NoteValue, RhythmicValue, TRIPLET};
+///
+/// let double_dotted_half = RhythmicValue { value: NoteValue::Half, dots: 2, tuplet: None };
+/// let triplet_eighth = RhythmicValue { value: NoteValue::Eighth, dots: 0, tuplet: Some(TRIPLET) };
+///
+/// assert_eq!(double_dotted_half.duration(), Duration::new(7, 8));
+/// assert_eq!(triplet_eighth.duration(), Duration::new(1, 12));
+/// assert_eq!(double_dotted_half.to_string(), "double-dotted half");
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct RhythmicValue {
+    pub value: NoteValue,
+    pub dots: Scalar,
+    pub tuplet: Option<Tuplet>,
+}
+
+/// The most dots a rhythmic value can have.
+pub const MAX_DOTS: Scalar = 4;
+
+impl RhythmicValue {
+    /// Returns the duration of the value, including its dots and tuplet.
+    ///
+    /// Panics if the value has fewer than zero or more than [`MAX_DOTS`] dots.
+    pub fn duration(&self) -> Duration {
+        assert!(
+            (0..=MAX_DOTS).contains(&self.dots),
+            "A rhythmic value must have between 0 and {MAX_DOTS} dots"
+        );
+
+        let undotted = Duration::from(self.value);
+
+        // Every dot adds half of the previous addition
+        let dotted = undotted * ((1 << (self.dots + 1)) - 1) / (1 << self.dots);
+
+        match self.tuplet {
+            None => dotted,
+            Some(tuplet) => dotted * tuplet.normal / tuplet.actual,
+        }
+    }
+
+    /// Returns the values that, tied together, make up a duration, longest first.
+    /// Durations that need a tuplet are written entirely in that tuplet.
+    /// Returns `None` for durations that can't be written with 128th notes and a single kind of tuplet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::rhythm::{Duration, NoteValue, RhythmicValue, TRIPLET};
+    ///
+    /// assert_eq!(
+    ///     RhythmicValue::tied(Duration::new(5, 8)),
+    ///     Some(vec![NoteValue::Half.into(), NoteValue::Eighth.into()]),
+    /// );
+    /// assert_eq!(
+    ///     RhythmicValue::tied(Duration::new(1, 6)),
+    ///     Some(vec![RhythmicValue { value: NoteValue::Quarter, dots: 0, tuplet: Some(TRIPLET) }]),
+    /// );
+    /// ```
+    pub fn tied(duration: Duration) -> Option<Vec<RhythmicValue>> {
+        if duration <= Duration::ZERO {
+            return None;
+        }
+
+        if duration.is_binary() {
+            return tied_binary(duration, None);
+        }
+
+        let mut actual = duration.denominator();
+
+        while actual % 2 == 0 {
+            actual /= 2;
+        }
+
+        let mut normal = 1;
+
+        while normal * 2 < actual {
+            normal *= 2;
+        }
+
+        let tuplet = Tuplet { actual, normal };
+
+        let written = duration * actual / normal;
+
+        match written.is_binary() {
+            true => tied_binary(written, Some(tuplet)),
+            false => None,
+        }
+    }
+}
+
+/// Greedily takes the longest value, with up to two dots, that still fits.
+fn tied_binary(duration: Duration, tuplet: Option<Tuplet>) -> Option<Vec<RhythmicValue>> {
+    let candidates = NOTE_VALUES
+        .iter()
+        .flat_map(|value| {
+            (0..=2).rev().map(move |dots| RhythmicValue {
+                value: *value,
+                dots,
+                tuplet: None,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut remaining = duration;
+    let mut values = vec![];
+
+    while remaining > Duration::ZERO {
+        let value = candidates
+            .iter()
+            .find(|candidate| candidate.duration() <= remaining)?;
+
+        remaining = remaining - value.duration();
+
+        values.push(RhythmicValue { tuplet, ..*value });
+    }
+
+    Some(values)
+}
+
+impl From<NoteValue> for RhythmicValue {
+    fn from(value: NoteValue) -> Self {
+        RhythmicValue {
+            value,
+            dots: 0,
+            tuplet: None,
+        }
+    }
+}
+
+impl Display for RhythmicValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.dots {
+            0 => {}
+            1 => f.write_str("dotted ")?,
+            2 => f.write_str("double-dotted ")?,
+            dots => write!(f, "{}-dotted ", dots)?,
+        }
+
+        write!(f, "{}", self.value)?;
+
+        match self.tuplet {
+            Some(tuplet) => write!(f, " ({})", tuplet),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rhythm::{Duration, NoteValue, RhythmicValue, Tuplet};
+
+    #[test]
+    fn durations() {
+        assert_eq!(
+            Duration::from(NoteValue::HundredTwentyEighth),
+            Duration::new(1, 128)
+        );
+        assert_eq!(
+            RhythmicValue {
+                value: NoteValue::Quarter,
+                dots: 1,
+                tuplet: None
+            }
+            .duration(),
+            Duration::new(3, 8)
+        );
+        assert_eq!(
+            RhythmicValue {
+                value: NoteValue::Sixteenth,
+                dots: 0,
+                tuplet: Some(Tuplet {
+                    actual: 5,
+                    normal: 4
+                })
+            }
+            .duration(),
+            Duration::new(1, 20)
+        );
+    }
+
+    #[test]
+    fn tied() {
+        assert_eq!(
+            RhythmicValue::tied(Duration::new(7, 8)),
+            Some(vec![RhythmicValue {
+                value: NoteValue::Half,
+                dots: 2,
+                tuplet: None
+            }])
+        );
+        assert_eq!(
+            RhythmicValue::tied(Duration::new(9, 4)),
+            Some(vec![
+                RhythmicValue {
+                    value: NoteValue::Whole,
+                    dots: 2,
+                    tuplet: None
+                },
+                NoteValue::Half.into()
+            ])
+        );
+        assert_eq!(
+            RhythmicValue::tied(Duration::new(1, 10))
+                .unwrap()
+                .iter()
+                .map(|value| value.duration())
+                .sum::<Duration>(),
+            Duration::new(1, 10)
+        );
+        assert_eq!(RhythmicValue::tied(Duration::new(1, 256)), None);
+        assert_eq!(RhythmicValue::tied(Duration::ZERO), None);
+    }
+
+    #[test]
+    fn quadruple_dotted() {
+        assert_eq!(
+            RhythmicValue {
+                value: NoteValue::Whole,
+                dots: 4,
+                tuplet: None
+            }
+            .duration(),
+            Duration::new(31, 16)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_dots() {
+        RhythmicValue {
+            value: NoteValue::Quarter,
+            dots: -1,
+            tuplet: None,
+        }
+        .duration();
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_dots() {
+        RhythmicValue {
+            value: NoteValue::Quarter,
+            dots: 40,
+            tuplet: None,
+        }
+        .duration();
+    }
+}
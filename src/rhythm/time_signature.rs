@@ -0,0 +1,199 @@
+use crate::common::Scalar;
+use crate::rhythm::Duration;
+use std::fmt::{Display, Formatter};
+
+/// Whether the beats of a meter divide into two or three, or are grouped unevenly.
+/// See [metre](https://en.wikipedia.org/wiki/Metre_(music)).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Meter {
+    Simple,
+    Compound,
+    Additive,
+}
+
+/// A [time signature](https://en.wikipedia.org/wiki/Time_signature) such as 4/4, 6/8 or the additive 2+3/8.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::rhythm::{Duration, Meter, TimeSignature};
+///
+/// let six_eight = TimeSignature::new(6, 8);
+///
+/// assert_eq!(six_eight.meter(), Meter::Compound);
+/// assert_eq!(six_eight.beats(), vec![Duration::new(3, 8), Duration::new(3, 8)]);
+///
+/// let additive = TimeSignature::try_from("2+3/8").unwrap();
+///
+/// assert_eq!(additive.meter(), Meter::Additive);
+/// assert_eq!(additive.duration(), Duration::new(5, 8));
+/// assert_eq!(additive.to_string(), "2+3/8");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TimeSignature {
+    /// The groups of the numerator, which has a single group unless the meter is additive.
+    pub numerators: Vec<Scalar>,
+    pub denominator: Scalar,
+}
+
+impl TimeSignature {
+    pub fn new(numerator: Scalar, denominator: Scalar) -> Self {
+        Self::additive(&[numerator], denominator)
+    }
+
+    pub fn additive(numerators: &[Scalar], denominator: Scalar) -> Self {
+        assert!(
+            !numerators.is_empty() && numerators.iter().all(|numerator| *numerator > 0),
+            "A time signature needs positive numerators",
+        );
+        assert!(
+            denominator > 0 && denominator & (denominator - 1) == 0,
+            "The denominator of a time signature is a power of two",
+        );
+
+        TimeSignature {
+            numerators: numerators.to_vec(),
+            denominator,
+        }
+    }
+
+    /// Returns the total number of beat units in a measure.
+    pub fn numerator(&self) -> Scalar {
+        self.numerators.iter().sum()
+    }
+
+    pub fn meter(&self) -> Meter {
+        if self.numerators.len() > 1 {
+            Meter::Additive
+        } else if self.numerator() % 3 == 0 && self.numerator() > 3 {
+            Meter::Compound
+        } else {
+            Meter::Simple
+        }
+    }
+
+    /// Returns the length of a full measure.
+    pub fn duration(&self) -> Duration {
+        Duration::new(self.numerator(), self.denominator)
+    }
+
+    /// Returns the lengths of the beats in a measure.
+    pub fn beats(&self) -> Vec<Duration> {
+        let unit = Duration::new(1, self.denominator);
+
+        match self.meter() {
+            Meter::Simple => vec![unit; self.numerator() as usize],
+            Meter::Compound => vec![unit * 3; self.numerator() as usize / 3],
+            Meter::Additive => self
+                .numerators
+                .iter()
+                .map(|numerator| unit * *numerator)
+                .collect(),
+        }
+    }
+
+    /// Returns the lengths of the groups that short notes are beamed in by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::rhythm::{Duration, TimeSignature};
+    ///
+    /// assert_eq!(TimeSignature::new(4, 4).beaming_groups(), vec![Duration::new(1, 2); 2]);
+    /// assert_eq!(TimeSignature::new(3, 8).beaming_groups(), vec![Duration::new(3, 8)]);
+    /// assert_eq!(TimeSignature::new(3, 4).beaming_groups(), vec![Duration::new(1, 4); 3]);
+    /// ```
+    pub fn beaming_groups(&self) -> Vec<Duration> {
+        match (self.meter(), self.numerator()) {
+            (Meter::Simple, 4) => vec![self.duration() / 2; 2],
+            (Meter::Simple, 3) if self.denominator >= 8 => vec![self.duration()],
+            _ => self.beats(),
+        }
+    }
+}
+
+impl Display for TimeSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let numerators = self
+            .numerators
+            .iter()
+            .map(|numerator| numerator.to_string())
+            .collect::<Vec<_>>();
+
+        write!(f, "{}/{}", numerators.join("+"), self.denominator)
+    }
+}
+
+impl TryFrom<&str> for TimeSignature {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (numerators, denominator) = value
+            .split_once('/')
+            .ok_or("A time signature needs a slash")?;
+
+        let numerators = numerators
+            .split('+')
+            .map(|numerator| numerator.trim().parse::<Scalar>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "Could not parse the numerator")?;
+
+        let denominator = denominator
+            .trim()
+            .parse::<Scalar>()
+            .map_err(|_| "Could not parse the denominator")?;
+
+        if numerators.iter().any(|numerator| *numerator <= 0) {
+            return Err("The numerators need to be positive");
+        }
+
+        if denominator <= 0 || denominator & (denominator - 1) != 0 {
+            return Err("The denominator needs to be a power of two");
+        }
+
+        Ok(TimeSignature {
+            numerators,
+            denominator,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rhythm::{Duration, Meter, TimeSignature};
+
+    #[test]
+    fn meters() {
+        assert_eq!(TimeSignature::new(3, 4).meter(), Meter::Simple);
+        assert_eq!(TimeSignature::new(12, 8).meter(), Meter::Compound);
+        assert_eq!(TimeSignature::new(12, 8).beats().len(), 4);
+        assert_eq!(
+            TimeSignature::additive(&[3, 2, 2], 8).meter(),
+            Meter::Additive
+        );
+    }
+
+    #[test]
+    fn beaming() {
+        assert_eq!(
+            TimeSignature::additive(&[3, 2, 2], 8).beaming_groups(),
+            vec![
+                Duration::new(3, 8),
+                Duration::new(1, 4),
+                Duration::new(1, 4)
+            ]
+        );
+        assert_eq!(
+            TimeSignature::new(6, 8).beaming_groups(),
+            vec![Duration::new(3, 8); 2]
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(TimeSignature::try_from("6/8"), Ok(TimeSignature::new(6, 8)));
+        assert!(TimeSignature::try_from("3/5").is_err());
+        assert!(TimeSignature::try_from("x/4").is_err());
+        assert!(TimeSignature::try_from("4").is_err());
+    }
+}
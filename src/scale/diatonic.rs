@@ -0,0 +1,154 @@
+use crate::pitch::{Pitch, PitchClass, PitchClassUtils, PitchSet};
+use crate::scale::Scale;
+use crate::vertical::{
+    InSemitones, InSteps, Semitones, SemitonesFromC0, Steps, StepsFromC, StepsFromC0,
+};
+
+pub trait TransposedWithin {
+    /// Returns the value moved by a number of scale degrees within a scale.
+    ///
+    /// Pitches in the scale land on other pitches in the scale,
+    /// while chromatically altered pitches keep their alteration.
+    fn transposed_within(&self, scale: &Scale, degrees: Steps) -> Self;
+}
+
+/// # Example
+///
+/// ```
+/// use solfege::key::Key;
+/// use solfege::pitch::PitchClassUtils;
+/// use solfege::pitch::PitchRoot::{B, D, E, G};
+/// use solfege::scale::TransposedWithin;
+/// use solfege::vertical::Steps;
+///
+/// let scale = Key::try_from("C major").unwrap().scale();
+///
+/// // Up a third
+/// assert_eq!(E.o(4).transposed_within(&scale, Steps(2)), G.o(4));
+/// assert_eq!(B.o(4).transposed_within(&scale, Steps(2)), D.o(5));
+/// ```
+impl TransposedWithin for Pitch {
+    fn transposed_within(&self, scale: &Scale, degrees: Steps) -> Self {
+        let intervals = scale.kind.intervals();
+        let len = intervals.len() as i32;
+
+        let relative_steps = self.steps_from_c0() - scale.tonic.steps_from_c();
+        let octave = relative_steps.0.div_euclid(7);
+        let steps_within_octave = relative_steps.0.rem_euclid(7);
+
+        // The degree on the same root, or the nearest degree below it if the scale skips the root
+        let index = intervals
+            .iter()
+            .rposition(|interval| interval.in_steps().0 <= steps_within_octave)
+            .expect("Scales start on the unison") as i32;
+
+        let target = octave * len + index + degrees.0;
+        let target_octave = target.div_euclid(len);
+        let target_index = target.rem_euclid(len);
+
+        let from = &intervals[index as usize];
+        let to = &intervals[target_index as usize];
+
+        let delta_steps = Steps((target_octave - octave) * 7) + to.in_steps() - from.in_steps();
+        let delta_semitones =
+            Semitones((target_octave - octave) * 12) + to.in_semitones() - from.in_semitones();
+
+        Pitch::from_c0(
+            self.steps_from_c0() + delta_steps,
+            self.semitones_from_c0() + delta_semitones,
+        )
+    }
+}
+
+impl TransposedWithin for PitchClass {
+    fn transposed_within(&self, scale: &Scale, degrees: Steps) -> Self {
+        self.o(4).transposed_within(scale, degrees).class
+    }
+}
+
+impl TransposedWithin for PitchSet {
+    fn transposed_within(&self, scale: &Scale, degrees: Steps) -> Self {
+        self.iter()
+            .map(|pitch| pitch.transposed_within(scale, degrees))
+            .collect()
+    }
+}
+
+impl TransposedWithin for Vec<Pitch> {
+    fn transposed_within(&self, scale: &Scale, degrees: Steps) -> Self {
+        self.iter()
+            .map(|pitch| pitch.transposed_within(scale, degrees))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::key::Key;
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassUtils, PitchRootUtils, PitchSet};
+    use crate::scale::{Scale, ScaleKind, TransposedWithin};
+    use crate::vertical::Steps;
+
+    fn scale(key: &str) -> Scale {
+        Key::try_from(key).unwrap().scale()
+    }
+
+    #[test]
+    fn keeps_the_key_signature() {
+        let f_major = scale("F major");
+
+        assert_eq!(B.flat().transposed_within(&f_major, Steps(1)), C.natural());
+        assert_eq!(A.o(4).transposed_within(&f_major, Steps(1)), B.flat().o(4));
+        assert_eq!(E.o(4).transposed_within(&f_major, Steps(-3)), B.flat().o(3));
+    }
+
+    #[test]
+    fn octave_carry() {
+        let c_major = scale("C major");
+
+        assert_eq!(C.o(4).transposed_within(&c_major, Steps(-1)), B.o(3));
+        assert_eq!(G.o(4).transposed_within(&c_major, Steps(7)), G.o(5));
+        assert_eq!(G.o(4).transposed_within(&c_major, Steps(-15)), F.o(2));
+    }
+
+    #[test]
+    fn chromatic_alterations() {
+        let c_major = scale("C major");
+
+        assert_eq!(
+            C.sharp().o(4).transposed_within(&c_major, Steps(1)),
+            D.sharp().o(4)
+        );
+        assert_eq!(
+            B.flat().o(4).transposed_within(&c_major, Steps(2)),
+            D.flat().o(5)
+        );
+    }
+
+    #[test]
+    fn non_heptatonic_scales() {
+        let pentatonic = Scale {
+            tonic: C.natural(),
+            kind: ScaleKind::MajorPentatonic,
+        };
+
+        assert_eq!(E.o(4).transposed_within(&pentatonic, Steps(1)), G.o(4));
+        assert_eq!(A.o(4).transposed_within(&pentatonic, Steps(1)), C.o(5));
+    }
+
+    #[test]
+    fn sets_and_sequences() {
+        let c_major = scale("C major");
+
+        assert_eq!(
+            PitchSet::from([C.o(4), E.o(4), G.o(4)]).transposed_within(&c_major, Steps(1)),
+            PitchSet::from([D.o(4), F.o(4), A.o(4)]),
+        );
+
+        assert_eq!(
+            vec![E.o(4), D.o(4), C.o(4), B.o(3)].transposed_within(&c_major, Steps(2)),
+            vec![G.o(4), F.o(4), E.o(4), D.o(4)],
+        );
+    }
+}
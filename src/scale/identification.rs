@@ -0,0 +1,242 @@
+use crate::common::Scalar;
+use crate::pitch::PitchRoot::{A, B, C, D, E, F, G};
+use crate::pitch::{PitchClass, PitchClassSet, PitchRootUtils, PitchSet};
+use crate::scale::{Scale, SCALE_KINDS};
+use std::collections::BTreeSet;
+
+/// Whether pitch classes are compared by their spelling, or only by how they sound.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Spelling {
+    /// G♯ and A♭ are different pitch classes.
+    Aware,
+    /// G♯ and A♭ are the same pitch class.
+    Agnostic,
+}
+
+/// How well a scale fits a collection of pitch classes.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum ScaleFit {
+    /// The scale consists of exactly the given pitch classes.
+    Exact,
+    /// The scale contains all the given pitch classes, plus a number of missing ones.
+    Superset { missing: usize },
+}
+
+/// A scale which contains a collection of pitch classes, along with how well it fits.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ScaleMatch {
+    pub scale: Scale,
+    pub fit: ScaleFit,
+}
+
+pub trait IdentifyScales {
+    /// Returns every scale that contains the pitch classes, with the best fitting scales first.
+    fn identify_scales(&self, spelling: Spelling) -> Vec<ScaleMatch>;
+}
+
+/// # Example
+///
+/// ```
+/// use solfege::pitch::PitchRoot::{A, B, C, D, E, G};
+/// use solfege::pitch::{PitchClassSet, PitchRootUtils};
+/// use solfege::scale::{IdentifyScales, ScaleFit, ScaleKind, Spelling};
+///
+/// let fragment = PitchClassSet::from([
+///     D.natural(), E.natural(), G.natural(), A.natural(), B.natural(),
+/// ]);
+///
+/// let matches = fragment.identify_scales(Spelling::Aware);
+///
+/// assert_eq!(matches[0].scale.tonic, G.natural());
+/// assert_eq!(matches[0].scale.kind, ScaleKind::MajorPentatonic);
+/// assert_eq!(matches[0].fit, ScaleFit::Exact);
+/// assert!(matches.iter().all(|m| m.fit <= ScaleFit::Superset { missing: 2 }));
+/// ```
+impl IdentifyScales for PitchClassSet {
+    fn identify_scales(&self, spelling: Spelling) -> Vec<ScaleMatch> {
+        let mut matches = match spelling {
+            Spelling::Aware => candidate_scales()
+                .into_iter()
+                .filter_map(|scale| {
+                    let classes = scale.pitch_classes();
+
+                    self.is_subset(&classes).then(|| ScaleMatch {
+                        scale,
+                        fit: fit(classes.len(), self.len()),
+                    })
+                })
+                .collect::<Vec<_>>(),
+            Spelling::Agnostic => {
+                let integers = integer_notations(self);
+
+                let mut matches: Vec<ScaleMatch> = vec![];
+
+                for scale in candidate_scales() {
+                    let scale_integers = integer_notations(&scale.pitch_classes());
+
+                    if !integers.is_subset(&scale_integers) {
+                        continue;
+                    }
+
+                    let candidate = ScaleMatch {
+                        scale,
+                        fit: fit(scale_integers.len(), integers.len()),
+                    };
+
+                    // Only keep the most simply spelled version of each scale
+                    match matches.iter_mut().find(|existing| {
+                        existing.scale.kind == scale.kind
+                            && existing.scale.tonic.is_enharmonic_to(&scale.tonic)
+                    }) {
+                        Some(existing) => {
+                            if accidental_count(&scale) < accidental_count(&existing.scale) {
+                                *existing = candidate;
+                            }
+                        }
+                        None => matches.push(candidate),
+                    }
+                }
+
+                matches
+            }
+        };
+
+        matches.sort_by_key(|m| {
+            (
+                m.fit,
+                !contains_tonic(self, &m.scale.tonic, spelling),
+                SCALE_KINDS.iter().position(|kind| *kind == m.scale.kind),
+                m.scale.tonic,
+            )
+        });
+
+        matches
+    }
+}
+
+impl IdentifyScales for PitchSet {
+    fn identify_scales(&self, spelling: Spelling) -> Vec<ScaleMatch> {
+        self.iter()
+            .map(|pitch| pitch.class)
+            .collect::<PitchClassSet>()
+            .identify_scales(spelling)
+    }
+}
+
+/// All scales on tonics with at most one flat or sharp.
+fn candidate_scales() -> Vec<Scale> {
+    let mut scales = vec![];
+
+    for root in [C, D, E, F, G, A, B] {
+        for tonic in [root.flat(), root.natural(), root.sharp()] {
+            for kind in SCALE_KINDS {
+                scales.push(Scale { tonic, kind });
+            }
+        }
+    }
+
+    scales
+}
+
+fn fit(scale_len: usize, collection_len: usize) -> ScaleFit {
+    match scale_len - collection_len {
+        0 => ScaleFit::Exact,
+        missing => ScaleFit::Superset { missing },
+    }
+}
+
+fn integer_notations(set: &PitchClassSet) -> BTreeSet<Scalar> {
+    set.iter().map(|class| class.integer_notation()).collect()
+}
+
+fn accidental_count(scale: &Scale) -> Scalar {
+    scale
+        .degrees()
+        .iter()
+        .map(|class| class.accidental.offset.0.abs())
+        .sum()
+}
+
+fn contains_tonic(set: &PitchClassSet, tonic: &PitchClass, spelling: Spelling) -> bool {
+    match spelling {
+        Spelling::Aware => set.contains(tonic),
+        Spelling::Agnostic => set.iter().any(|class| class.is_enharmonic_to(tonic)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClassSet, PitchClassUtils, PitchRootUtils, PitchSet};
+    use crate::scale::{IdentifyScales, Scale, ScaleFit, ScaleKind, Spelling};
+
+    #[test]
+    fn exact_match_comes_first() {
+        let matches = C.major().identify_scales(Spelling::Aware);
+
+        assert_eq!(
+            matches[0].scale,
+            Scale {
+                tonic: C.natural(),
+                kind: ScaleKind::Major,
+            },
+        );
+        assert_eq!(matches[0].fit, ScaleFit::Exact);
+
+        // The relative modes are also exact matches
+        assert_eq!(
+            matches.iter().filter(|m| m.fit == ScaleFit::Exact).count(),
+            7,
+        );
+    }
+
+    #[test]
+    fn spelling_aware() {
+        let set = PitchClassSet::from([G.sharp(), C.natural(), D.sharp()]);
+
+        let matches = set.identify_scales(Spelling::Aware);
+
+        assert!(matches
+            .iter()
+            .all(|m| m.scale.pitch_classes().is_superset(&set)));
+        assert!(!matches.iter().any(|m| m.scale
+            == Scale {
+                tonic: A.flat(),
+                kind: ScaleKind::Major,
+            }));
+    }
+
+    #[test]
+    fn spelling_agnostic() {
+        let set = PitchClassSet::from([G.sharp(), C.natural(), D.sharp()]);
+
+        let matches = set.identify_scales(Spelling::Agnostic);
+
+        assert!(matches.iter().any(|m| m.scale
+            == Scale {
+                tonic: A.flat(),
+                kind: ScaleKind::Major,
+            }));
+        assert!(!matches.iter().any(|m| m.scale
+            == Scale {
+                tonic: G.sharp(),
+                kind: ScaleKind::Major,
+            }));
+    }
+
+    #[test]
+    fn from_pitch_set() {
+        let set = PitchSet::from([A.o(3), C.o(4), E.o(4), G.sharp().o(4), A.o(4)]);
+
+        let matches = set.identify_scales(Spelling::Aware);
+
+        assert_eq!(
+            matches[0].scale,
+            Scale {
+                tonic: A.natural(),
+                kind: ScaleKind::HarmonicMinor,
+            },
+        );
+        assert_eq!(matches[0].fit, ScaleFit::Superset { missing: 3 });
+    }
+}
@@ -0,0 +1,10 @@
+mod diatonic;
+mod identification;
+#[allow(clippy::module_inception)]
+mod scale;
+mod scale_kind;
+
+pub use self::diatonic::*;
+pub use self::identification::*;
+pub use self::scale::*;
+pub use self::scale_kind::*;
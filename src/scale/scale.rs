@@ -0,0 +1,95 @@
+use crate::pitch::{PitchClass, PitchClassSet};
+use crate::scale::ScaleKind;
+use crate::vertical::TransposedBy;
+use std::fmt::{Display, Formatter};
+
+/// A [scale](https://en.wikipedia.org/wiki/Scale_(music)) built on a tonic pitch class.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::pitch::PitchRoot::{B, C, D, F};
+/// use solfege::pitch::PitchRootUtils;
+/// use solfege::scale::{Scale, ScaleKind};
+///
+/// let scale = Scale { tonic: D.natural(), kind: ScaleKind::HarmonicMinor };
+///
+/// assert_eq!(scale.degrees()[2], F.natural());
+/// assert_eq!(scale.degrees()[5], B.flat());
+/// assert_eq!(scale.degrees()[6], C.sharp());
+/// assert_eq!(scale.to_string(), "D♮ harmonic minor");
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Scale {
+    pub tonic: PitchClass,
+    pub kind: ScaleKind,
+}
+
+impl Scale {
+    /// Returns the pitch classes of the scale in ascending order, starting from the tonic.
+    pub fn degrees(&self) -> Vec<PitchClass> {
+        self.kind
+            .intervals()
+            .iter()
+            .map(|interval| self.tonic.transposed_by(interval))
+            .collect()
+    }
+
+    /// Returns the pitch classes of the scale as a set.
+    pub fn pitch_classes(&self) -> PitchClassSet {
+        self.degrees().into_iter().collect()
+    }
+}
+
+impl Display for Scale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.tonic, self.kind)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::PitchRootUtils;
+    use crate::scale::{Scale, ScaleKind};
+
+    #[test]
+    fn degrees() {
+        let scale = Scale {
+            tonic: E.flat(),
+            kind: ScaleKind::Lydian,
+        };
+
+        assert_eq!(
+            scale.degrees(),
+            vec![
+                E.flat(),
+                F.natural(),
+                G.natural(),
+                A.natural(),
+                B.flat(),
+                C.natural(),
+                D.natural(),
+            ],
+        );
+    }
+
+    #[test]
+    fn pentatonic() {
+        let scale = Scale {
+            tonic: A.natural(),
+            kind: ScaleKind::MinorPentatonic,
+        };
+
+        assert_eq!(
+            scale.degrees(),
+            vec![
+                A.natural(),
+                C.natural(),
+                D.natural(),
+                E.natural(),
+                G.natural()
+            ],
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::interval::IntervalRoot::{Fifth, Fourth, Second, Seventh, Sixth, Third, Unison};
+use crate::interval::{augmented, diminished, major, minor, perfect, IntervalClass};
+use std::fmt::{Display, Formatter};
+
+/// The kind of a [scale](https://en.wikipedia.org/wiki/Scale_(music)) or [mode](https://en.wikipedia.org/wiki/Mode_(music)),
+/// defined by the intervals of its degrees above the tonic.
+///
+/// # Examples
+///
+/// ```
+/// use solfege::scale::ScaleKind;
+///
+/// assert_eq!(ScaleKind::Dorian.intervals().len(), 7);
+/// assert_eq!(ScaleKind::MajorPentatonic.to_string(), "major pentatonic");
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum ScaleKind {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    MelodicMinor,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Locrian,
+    MajorPentatonic,
+    MinorPentatonic,
+}
+
+/// All scale kinds, in order of how commonly they are used.
+pub const SCALE_KINDS: [ScaleKind; 11] = [
+    ScaleKind::Major,
+    ScaleKind::NaturalMinor,
+    ScaleKind::HarmonicMinor,
+    ScaleKind::MelodicMinor,
+    ScaleKind::Dorian,
+    ScaleKind::Phrygian,
+    ScaleKind::Lydian,
+    ScaleKind::Mixolydian,
+    ScaleKind::Locrian,
+    ScaleKind::MajorPentatonic,
+    ScaleKind::MinorPentatonic,
+];
+
+impl ScaleKind {
+    /// Returns the intervals of each degree above the tonic, in ascending order.
+    pub fn intervals(&self) -> Vec<IntervalClass> {
+        match *self {
+            Self::Major => vec![
+                perfect(Unison),
+                major(Second),
+                major(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                major(Sixth),
+                major(Seventh),
+            ],
+            Self::NaturalMinor => vec![
+                perfect(Unison),
+                major(Second),
+                minor(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                minor(Sixth),
+                minor(Seventh),
+            ],
+            Self::HarmonicMinor => vec![
+                perfect(Unison),
+                major(Second),
+                minor(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                minor(Sixth),
+                major(Seventh),
+            ],
+            Self::MelodicMinor => vec![
+                perfect(Unison),
+                major(Second),
+                minor(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                major(Sixth),
+                major(Seventh),
+            ],
+            Self::Dorian => vec![
+                perfect(Unison),
+                major(Second),
+                minor(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                major(Sixth),
+                minor(Seventh),
+            ],
+            Self::Phrygian => vec![
+                perfect(Unison),
+                minor(Second),
+                minor(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                minor(Sixth),
+                minor(Seventh),
+            ],
+            Self::Lydian => vec![
+                perfect(Unison),
+                major(Second),
+                major(Third),
+                augmented(Fourth),
+                perfect(Fifth),
+                major(Sixth),
+                major(Seventh),
+            ],
+            Self::Mixolydian => vec![
+                perfect(Unison),
+                major(Second),
+                major(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                major(Sixth),
+                minor(Seventh),
+            ],
+            Self::Locrian => vec![
+                perfect(Unison),
+                minor(Second),
+                minor(Third),
+                perfect(Fourth),
+                diminished(Fifth),
+                minor(Sixth),
+                minor(Seventh),
+            ],
+            Self::MajorPentatonic => vec![
+                perfect(Unison),
+                major(Second),
+                major(Third),
+                perfect(Fifth),
+                major(Sixth),
+            ],
+            Self::MinorPentatonic => vec![
+                perfect(Unison),
+                minor(Third),
+                perfect(Fourth),
+                perfect(Fifth),
+                minor(Seventh),
+            ],
+        }
+    }
+}
+
+impl Display for ScaleKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Major => "major",
+            Self::NaturalMinor => "natural minor",
+            Self::HarmonicMinor => "harmonic minor",
+            Self::MelodicMinor => "melodic minor",
+            Self::Dorian => "dorian",
+            Self::Phrygian => "phrygian",
+            Self::Lydian => "lydian",
+            Self::Mixolydian => "mixolydian",
+            Self::Locrian => "locrian",
+            Self::MajorPentatonic => "major pentatonic",
+            Self::MinorPentatonic => "minor pentatonic",
+        })
+    }
+}
+
+impl TryFrom<&str> for ScaleKind {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "major" | "ionian" => Ok(Self::Major),
+            "minor" | "natural minor" | "aeolian" => Ok(Self::NaturalMinor),
+            "harmonic minor" => Ok(Self::HarmonicMinor),
+            "melodic minor" => Ok(Self::MelodicMinor),
+            "dorian" => Ok(Self::Dorian),
+            "phrygian" => Ok(Self::Phrygian),
+            "lydian" => Ok(Self::Lydian),
+            "mixolydian" => Ok(Self::Mixolydian),
+            "locrian" => Ok(Self::Locrian),
+            "major pentatonic" => Ok(Self::MajorPentatonic),
+            "minor pentatonic" => Ok(Self::MinorPentatonic),
+            _ => Err("Could not recognise scale kind"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ScaleKind, SCALE_KINDS};
+    use crate::vertical::InSemitones;
+
+    #[test]
+    fn intervals_ascend() {
+        for kind in SCALE_KINDS {
+            let semitones = kind
+                .intervals()
+                .iter()
+                .map(|interval| interval.in_semitones())
+                .collect::<Vec<_>>();
+
+            assert!(semitones.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(ScaleKind::try_from("Aeolian"), Ok(ScaleKind::NaturalMinor));
+        assert_eq!(
+            ScaleKind::try_from("melodic minor"),
+            Ok(ScaleKind::MelodicMinor)
+        );
+        assert!(ScaleKind::try_from("bebop").is_err());
+    }
+}
@@ -0,0 +1,3 @@
+mod tone_row;
+
+pub use self::tone_row::*;
@@ -0,0 +1,542 @@
+use crate::common::Scalar;
+use crate::pitch::PitchRoot::{A, B, C, D, E, F, G};
+use crate::pitch::{PitchClass, PitchClassSet, PitchRootUtils};
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+/// One of the four basic forms of a [tone row](https://en.wikipedia.org/wiki/Tone_row).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum RowForm {
+    Prime,
+    Inversion,
+    Retrograde,
+    RetrogradeInversion,
+}
+
+pub const ROW_FORMS: [RowForm; 4] = [
+    RowForm::Prime,
+    RowForm::Inversion,
+    RowForm::Retrograde,
+    RowForm::RetrogradeInversion,
+];
+
+impl Display for RowForm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Prime => "P",
+            Self::Inversion => "I",
+            Self::Retrograde => "R",
+            Self::RetrogradeInversion => "RI",
+        })
+    }
+}
+
+/// How the transposition of a row form is numbered.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Labeling {
+    /// Numbers count semitones up from the original row, so P0 is the row itself,
+    /// and R*n* and RI*n* are the retrogrades of P*n* and I*n*.
+    Babbitt,
+    /// Numbers are the integer notation of the first pitch class of each form, with C as 0.
+    Perle,
+}
+
+/// A row form at a transposition, e.g. RI5.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct RowLabel {
+    pub form: RowForm,
+    pub transposition: Scalar,
+}
+
+impl Display for RowLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.form, self.transposition)
+    }
+}
+
+impl TryFrom<&str> for RowLabel {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (form, number) = if let Some(number) = value.strip_prefix("RI") {
+            (RowForm::RetrogradeInversion, number)
+        } else if let Some(number) = value.strip_prefix('R') {
+            (RowForm::Retrograde, number)
+        } else if let Some(number) = value.strip_prefix('I') {
+            (RowForm::Inversion, number)
+        } else if let Some(number) = value.strip_prefix('P') {
+            (RowForm::Prime, number)
+        } else {
+            return Err("Could not recognise row form");
+        };
+
+        let transposition = number
+            .parse::<Scalar>()
+            .map_err(|_| "Could not parse transposition")?;
+
+        if !(0..12).contains(&transposition) {
+            return Err("Transpositions range from 0 to 11");
+        }
+
+        Ok(RowLabel {
+            form,
+            transposition,
+        })
+    }
+}
+
+/// How pitch classes are spelled when a row is respelled.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum RowSpelling {
+    /// Black keys are spelled as sharps.
+    Sharps,
+    /// Black keys are spelled as flats.
+    Flats,
+}
+
+impl RowSpelling {
+    /// Returns the pitch class with the given integer notation.
+    pub fn spell(&self, integer: Scalar) -> PitchClass {
+        match (integer.rem_euclid(12), self) {
+            (0, _) => C.natural(),
+            (1, Self::Sharps) => C.sharp(),
+            (1, Self::Flats) => D.flat(),
+            (2, _) => D.natural(),
+            (3, Self::Sharps) => D.sharp(),
+            (3, Self::Flats) => E.flat(),
+            (4, _) => E.natural(),
+            (5, _) => F.natural(),
+            (6, Self::Sharps) => F.sharp(),
+            (6, Self::Flats) => G.flat(),
+            (7, _) => G.natural(),
+            (8, Self::Sharps) => G.sharp(),
+            (8, Self::Flats) => A.flat(),
+            (9, _) => A.natural(),
+            (10, Self::Sharps) => A.sharp(),
+            (10, Self::Flats) => B.flat(),
+            _ => B.natural(),
+        }
+    }
+}
+
+/// Whether the first hexachord of a row can be completed to an aggregate by the first hexachord of another of its forms.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Combinatoriality {
+    /// By a transposition of the prime form other than the row itself.
+    pub prime: bool,
+    /// By a transposition of the inversion.
+    pub inversion: bool,
+    /// Always true, as the retrograde of any row starts with the second hexachord of the row itself.
+    pub retrograde: bool,
+    /// By a transposition of the retrograde inversion.
+    pub retrograde_inversion: bool,
+}
+
+impl Combinatoriality {
+    pub fn is_semi_combinatorial(&self) -> bool {
+        self.prime || self.inversion || self.retrograde_inversion
+    }
+
+    pub fn is_all_combinatorial(&self) -> bool {
+        self.prime && self.inversion && self.retrograde && self.retrograde_inversion
+    }
+}
+
+/// A [twelve-tone row](https://en.wikipedia.org/wiki/Tone_row); an ordering of all twelve pitch classes.
+///
+/// Every form of the row keeps the spelling the row uses for each pitch class,
+/// unless it is [respelled](ToneRow::respelled).
+///
+/// # Examples
+///
+/// ```
+/// use solfege::serial::{Labeling, RowLabel, ToneRow};
+///
+/// // Berg, Violin Concerto
+/// let row = ToneRow::try_from("G B♭ D F♯ A C E G♯ B C♯ E♭ F").unwrap();
+///
+/// let label = RowLabel::try_from("RI3").unwrap();
+///
+/// assert_eq!(
+///     row.form(&label, Labeling::Babbitt).to_string(),
+///     "C♮ D♮ E♮ F♯ A♮ C♯ F♮ G♯ B♮ E♭ G♮ B♭",
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ToneRow {
+    classes: Vec<PitchClass>,
+}
+
+impl ToneRow {
+    /// Creates a row, which must contain each of the twelve pitch classes exactly once.
+    pub fn new(classes: Vec<PitchClass>) -> Self {
+        let integers = classes
+            .iter()
+            .map(|class| class.integer_notation())
+            .collect::<BTreeSet<_>>();
+
+        assert!(
+            classes.len() == 12 && integers.len() == 12,
+            "A tone row must contain all twelve pitch classes exactly once",
+        );
+
+        ToneRow { classes }
+    }
+
+    pub fn pitch_classes(&self) -> &[PitchClass] {
+        &self.classes
+    }
+
+    pub fn integers(&self) -> Vec<Scalar> {
+        self.classes
+            .iter()
+            .map(|class| class.integer_notation())
+            .collect()
+    }
+
+    /// Returns the row with every pitch class spelled by the given policy.
+    pub fn respelled(&self, spelling: RowSpelling) -> ToneRow {
+        ToneRow {
+            classes: self
+                .integers()
+                .into_iter()
+                .map(|integer| spelling.spell(integer))
+                .collect(),
+        }
+    }
+
+    /// Returns the row form with the given label.
+    pub fn form(&self, label: &RowLabel, labeling: Labeling) -> ToneRow {
+        let integers = self.integers();
+        let first = integers[0];
+        let span = integers[11] - first;
+        let n = label.transposition;
+
+        // The pitch class each (unreversed) form starts on
+        let start = match (labeling, label.form) {
+            (Labeling::Babbitt, _) => first + n,
+            (Labeling::Perle, RowForm::Prime | RowForm::Inversion) => n,
+            (Labeling::Perle, RowForm::Retrograde) => n - span,
+            (Labeling::Perle, RowForm::RetrogradeInversion) => n + span,
+        };
+
+        let mut form = integers
+            .iter()
+            .map(|integer| match label.form {
+                RowForm::Prime | RowForm::Retrograde => start + integer - first,
+                RowForm::Inversion | RowForm::RetrogradeInversion => start - integer + first,
+            })
+            .collect::<Vec<_>>();
+
+        if matches!(
+            label.form,
+            RowForm::Retrograde | RowForm::RetrogradeInversion
+        ) {
+            form.reverse();
+        }
+
+        self.spelled(&form)
+    }
+
+    /// Returns all 48 forms of the row, ordered by form and transposition.
+    pub fn forms(&self, labeling: Labeling) -> Vec<(RowLabel, ToneRow)> {
+        ROW_FORMS
+            .iter()
+            .flat_map(|&form| {
+                (0..12).map(move |transposition| RowLabel {
+                    form,
+                    transposition,
+                })
+            })
+            .map(|label| (label, self.form(&label, labeling)))
+            .collect()
+    }
+
+    /// Returns the [twelve-tone matrix](https://en.wikipedia.org/wiki/Tone_row#Twelve-tone_matrix) of the row.
+    ///
+    /// The rows of the matrix are the prime forms, read backwards as retrogrades,
+    /// and the columns are the inversions, read backwards as retrograde inversions.
+    pub fn matrix(&self) -> Vec<ToneRow> {
+        let integers = self.integers();
+
+        integers
+            .iter()
+            .map(|row| {
+                let start = 2 * integers[0] - row;
+
+                self.spelled(
+                    &integers
+                        .iter()
+                        .map(|integer| start + integer - integers[0])
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns every form that contains the segment, with the index at which it starts, regardless of spelling.
+    pub fn identify(&self, segment: &[PitchClass], labeling: Labeling) -> Vec<(RowLabel, usize)> {
+        assert!(
+            !segment.is_empty() && segment.len() <= 12,
+            "A segment must contain between 1 and 12 pitch classes",
+        );
+
+        let segment = segment
+            .iter()
+            .map(|class| class.integer_notation())
+            .collect::<Vec<_>>();
+
+        self.forms(labeling)
+            .into_iter()
+            .flat_map(|(label, form)| {
+                form.integers()
+                    .windows(segment.len())
+                    .enumerate()
+                    .filter(|(_, window)| *window == segment.as_slice())
+                    .map(|(index, _)| (label, index))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns the forms whose first hexachord completes the aggregate with the first hexachord of the row,
+    /// leaving out the trivial retrograde of the row itself.
+    pub fn combinatorial_forms(&self, labeling: Labeling) -> Vec<RowLabel> {
+        let hexachord = self.first_hexachord();
+
+        self.forms(labeling)
+            .into_iter()
+            .filter(|(label, form)| {
+                let trivial = label.form == RowForm::Retrograde
+                    && form.classes.iter().rev().eq(self.classes.iter());
+
+                !trivial && hexachord.is_disjoint(&form.first_hexachord())
+            })
+            .map(|(label, _)| label)
+            .collect()
+    }
+
+    /// Returns by which forms the row is [hexachordally combinatorial](https://en.wikipedia.org/wiki/Combinatoriality).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solfege::serial::{Labeling, RowLabel, ToneRow};
+    ///
+    /// // Schoenberg, Piano Suite, Op. 25
+    /// let row = ToneRow::try_from("E F G D♭ G♭ E♭ A♭ D B C A B♭").unwrap();
+    ///
+    /// let combinatoriality = row.combinatoriality();
+    ///
+    /// assert!(combinatoriality.inversion);
+    /// assert!(!combinatoriality.is_all_combinatorial());
+    /// assert!(row
+    ///     .combinatorial_forms(Labeling::Babbitt)
+    ///     .contains(&RowLabel::try_from("I7").unwrap()));
+    /// ```
+    pub fn combinatoriality(&self) -> Combinatoriality {
+        let forms = self
+            .combinatorial_forms(Labeling::Babbitt)
+            .into_iter()
+            .map(|label| label.form)
+            .collect::<BTreeSet<_>>();
+
+        Combinatoriality {
+            prime: forms.contains(&RowForm::Prime),
+            inversion: forms.contains(&RowForm::Inversion),
+            retrograde: true,
+            retrograde_inversion: forms.contains(&RowForm::RetrogradeInversion),
+        }
+    }
+
+    fn first_hexachord(&self) -> BTreeSet<Scalar> {
+        self.integers().into_iter().take(6).collect()
+    }
+
+    fn spelled(&self, integers: &[Scalar]) -> ToneRow {
+        ToneRow {
+            classes: integers
+                .iter()
+                .map(|integer| {
+                    *self
+                        .classes
+                        .iter()
+                        .find(|class| class.integer_notation() == integer.rem_euclid(12))
+                        .unwrap()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&ToneRow> for PitchClassSet {
+    fn from(row: &ToneRow) -> Self {
+        row.classes.iter().copied().collect()
+    }
+}
+
+impl Display for ToneRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let classes = self
+            .classes
+            .iter()
+            .map(|class| class.to_string())
+            .collect::<Vec<_>>();
+
+        f.write_str(&classes.join(" "))
+    }
+}
+
+impl TryFrom<&str> for ToneRow {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let classes = value
+            .split_whitespace()
+            .map(PitchClass::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let integers = classes
+            .iter()
+            .map(|class| class.integer_notation())
+            .collect::<BTreeSet<_>>();
+
+        if classes.len() != 12 || integers.len() != 12 {
+            return Err("A tone row must contain all twelve pitch classes exactly once");
+        }
+
+        Ok(ToneRow { classes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pitch::PitchRoot::*;
+    use crate::pitch::{PitchClass, PitchRootUtils};
+    use crate::serial::{Labeling, RowForm, RowLabel, RowSpelling, ToneRow};
+
+    fn label(value: &str) -> RowLabel {
+        RowLabel::try_from(value).unwrap()
+    }
+
+    fn chromatic() -> ToneRow {
+        ToneRow::new(
+            (0..12)
+                .map(|integer| RowSpelling::Sharps.spell(integer))
+                .collect(),
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn repeated_pitch_class() {
+        ToneRow::new(vec![C.natural(); 12]);
+    }
+
+    #[test]
+    fn parsing() {
+        assert!(ToneRow::try_from("C D E").is_err());
+        assert!(ToneRow::try_from("C C♯ D D♯ E F F♯ G G♯ A A♯ B♯").is_err());
+        assert_eq!(
+            label("RI10"),
+            RowLabel {
+                form: RowForm::RetrogradeInversion,
+                transposition: 10
+            }
+        );
+        assert!(RowLabel::try_from("Q3").is_err());
+        assert!(RowLabel::try_from("P12").is_err());
+    }
+
+    #[test]
+    fn labelings() {
+        let row = chromatic();
+
+        // Babbitt numbers retrogrades after the form they reverse, Perle after their first pitch class
+        assert_eq!(
+            row.form(&label("R0"), Labeling::Babbitt),
+            row.form(&label("R11"), Labeling::Perle)
+        );
+        assert_eq!(
+            row.form(&label("RI0"), Labeling::Babbitt),
+            row.form(&label("RI1"), Labeling::Perle)
+        );
+        assert_eq!(
+            row.form(&label("I3"), Labeling::Babbitt).integers(),
+            vec![3, 2, 1, 0, 11, 10, 9, 8, 7, 6, 5, 4]
+        );
+
+        let forms = row.forms(Labeling::Perle);
+
+        assert_eq!(forms.len(), 48);
+        assert!(forms
+            .iter()
+            .all(|(label, form)| form.integers()[0] == label.transposition));
+    }
+
+    #[test]
+    fn matrix() {
+        let row = ToneRow::try_from("G B♭ D F♯ A C E G♯ B C♯ E♭ F").unwrap();
+        let matrix = row.matrix();
+
+        assert_eq!(matrix[0], row);
+        assert_eq!(matrix[1].to_string(), "E♮ G♮ B♮ E♭ F♯ A♮ C♯ F♮ G♯ B♭ C♮ D♮");
+
+        let first_column = matrix
+            .iter()
+            .map(|row| row.pitch_classes()[0])
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            first_column,
+            row.form(&label("I0"), Labeling::Babbitt).pitch_classes()
+        );
+    }
+
+    #[test]
+    fn spelling() {
+        let row = chromatic().respelled(RowSpelling::Flats);
+
+        assert_eq!(row.pitch_classes()[1], D.flat());
+        assert_eq!(
+            row.form(&label("P1"), Labeling::Babbitt).pitch_classes()[0],
+            D.flat()
+        );
+    }
+
+    #[test]
+    fn identification() {
+        let row = ToneRow::try_from("G B♭ D F♯ A C E G♯ B C♯ E♭ F").unwrap();
+        let segment: Vec<PitchClass> = vec![F.natural(), D.sharp(), D.flat()];
+
+        assert_eq!(
+            row.identify(&segment, Labeling::Babbitt),
+            vec![
+                (label("I2"), 8),
+                (label("I4"), 9),
+                (label("R0"), 0),
+                (label("R2"), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn combinatoriality() {
+        let chromatic = chromatic().combinatoriality();
+
+        assert!(chromatic.is_all_combinatorial());
+
+        let row = ToneRow::try_from("C C♯ D D♯ E F♯ B A♯ A G♯ G F").unwrap();
+
+        assert_eq!(
+            row.combinatorial_forms(Labeling::Babbitt),
+            vec![label("I11")]
+        );
+        assert!(!row.combinatoriality().prime);
+
+        let berg = ToneRow::try_from("G B♭ D F♯ A C E G♯ B C♯ E♭ F").unwrap();
+
+        assert!(!berg.combinatoriality().is_semi_combinatorial());
+        assert!(berg.combinatoriality().retrograde);
+    }
+}
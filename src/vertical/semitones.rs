@@ -23,7 +23,7 @@ pub trait SemitonesFromC0 {
 
 impl InOctaves for Semitones {
     fn in_octaves(&self) -> Octaves {
-        return Octaves(self.0 / 12);
+        Octaves(self.0 / 12)
     }
 }
 
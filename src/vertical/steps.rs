@@ -23,7 +23,7 @@ pub trait StepsFromC0 {
 
 impl InOctaves for Steps {
     fn in_octaves(&self) -> Octaves {
-        return Octaves(self.0 / 7);
+        Octaves(self.0 / 7)
     }
 }
 